@@ -0,0 +1,118 @@
+//! Handing out temporary typed arrays from a single preallocated buffer.
+
+use crate::{limit, TypedArray, TypedArrayKind};
+use core::cell::Cell;
+use err_derive::*;
+use js_sys::ArrayBuffer;
+
+/// Returned when a `ScratchArena` does not have enough bytes left for a typed
+/// array.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error(
+    display = "requested {} bytes from a scratch arena, but only {} are available",
+    requested,
+    available
+)]
+pub struct ArenaFullError {
+    requested: u64,
+    available: u32,
+}
+
+impl ArenaFullError {
+    /// The number of bytes that were requested, including any padding needed
+    /// to align the typed array.
+    pub fn requested(&self) -> u64 {
+        self.requested
+    }
+
+    /// The number of bytes that were left in the arena.
+    pub fn available(&self) -> u32 {
+        self.available
+    }
+}
+
+/// An arena that allocates one `ArrayBuffer` up front, and hands out typed
+/// arrays viewing consecutive parts of it. Handing out a typed array only
+/// moves an offset forward, so it allocates no memory.
+///
+/// All typed arrays are handed out for the current epoch. Calling [`reset`]
+/// starts a new epoch, for example at the start of every frame, after which
+/// the memory is handed out again. Typed arrays from an earlier epoch must
+/// not be used anymore, since they view the same memory as the new ones.
+///
+/// [`reset`]: #method.reset
+#[derive(Debug)]
+pub struct ScratchArena {
+    buffer: ArrayBuffer,
+    offset: Cell<u32>,
+    epoch: Cell<u64>,
+}
+
+impl ScratchArena {
+    /// Creates an arena with a buffer of `byte_capacity` bytes.
+    ///
+    /// # Panics
+    /// Panics if the buffer would exceed the limit set with
+    /// [`set_allocation_limit`].
+    ///
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    pub fn new(byte_capacity: u32) -> Self {
+        limit::assert_allocation(u64::from(byte_capacity));
+        ScratchArena {
+            buffer: ArrayBuffer::new(byte_capacity),
+            offset: Cell::new(0),
+            epoch: Cell::new(0),
+        }
+    }
+
+    /// Hands out a typed array of the given kind and length, aligned to the
+    /// element size. The elements are not cleared, so they may contain values
+    /// written during an earlier epoch.
+    pub fn alloc(&self, kind: TypedArrayKind, length: u32) -> Result<TypedArray, ArenaFullError> {
+        let bytes_per_element = u64::from(kind.bytes_per_element());
+        let offset = self.offset.get();
+        let begin = u64::from(offset).next_multiple_of(bytes_per_element);
+        let end = begin + u64::from(length) * bytes_per_element;
+        let available = self.byte_capacity() - offset;
+        if end <= u64::from(self.byte_capacity()) {
+            self.offset.set(end as u32);
+            Ok(TypedArray::new_with_buffer(
+                kind,
+                &self.buffer,
+                begin as u32,
+                length,
+            ))
+        } else {
+            Err(ArenaFullError {
+                requested: end - u64::from(offset),
+                available,
+            })
+        }
+    }
+
+    /// Starts a new epoch, after which all memory can be handed out again.
+    pub fn reset(&self) {
+        self.offset.set(0);
+        self.epoch.set(self.epoch.get() + 1);
+    }
+
+    /// The current epoch, which starts at zero and is incremented by every
+    /// [`reset`]. It can be stored alongside a typed array to check whether
+    /// the typed array is still valid.
+    ///
+    /// [`reset`]: #method.reset
+    pub fn epoch(&self) -> u64 {
+        self.epoch.get()
+    }
+
+    /// The number of bytes handed out during the current epoch, including
+    /// padding.
+    pub fn used(&self) -> u32 {
+        self.offset.get()
+    }
+
+    /// The size of the buffer of the arena in bytes.
+    pub fn byte_capacity(&self) -> u32 {
+        self.buffer.byte_length()
+    }
+}
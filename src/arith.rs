@@ -0,0 +1,173 @@
+//! Elementwise arithmetic between typed arrays.
+
+use crate::compare::zip_element_chunks;
+use crate::{chunk_ranges, KindMismatchError, TypedArray, CHUNK_LEN};
+
+/// An elementwise operation.
+#[derive(Copy, Clone)]
+pub(crate) enum Operation {
+    Add,
+    Sub,
+    Mul,
+}
+
+/// Arithmetic in which integers wrap around on overflow.
+trait Arithmetic: Copy {
+    fn apply(self, operation: Operation, other: Self) -> Self;
+}
+
+macro_rules! impl_wrapping_arithmetic {
+    ($($ty:ident),*) => {
+        $(
+            impl Arithmetic for $ty {
+                fn apply(self, operation: Operation, other: Self) -> Self {
+                    match operation {
+                        Operation::Add => self.wrapping_add(other),
+                        Operation::Sub => self.wrapping_sub(other),
+                        Operation::Mul => self.wrapping_mul(other),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_wrapping_arithmetic!(i8, u8, i16, u16, i32, u32);
+
+macro_rules! impl_float_arithmetic {
+    ($($ty:ident),*) => {
+        $(
+            impl Arithmetic for $ty {
+                fn apply(self, operation: Operation, other: Self) -> Self {
+                    match operation {
+                        Operation::Add => self + other,
+                        Operation::Sub => self - other,
+                        Operation::Mul => self * other,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_float_arithmetic!(f32, f64);
+
+/// Arithmetic that saturates instead, for the elements of a
+/// `Uint8ClampedArray`.
+fn apply_clamped(value: u8, operation: Operation, other: u8) -> u8 {
+    match operation {
+        Operation::Add => value.saturating_add(other),
+        Operation::Sub => value.saturating_sub(other),
+        Operation::Mul => value.saturating_mul(other),
+    }
+}
+
+impl TypedArray {
+    /// Adds every element of `other` to the corresponding element of this
+    /// typed array. Integers wrap around on overflow, except those of a
+    /// `Uint8ClampedArray`, which saturate.
+    ///
+    /// Returns an error if `other` is of a different kind.
+    ///
+    /// # Panics
+    /// Panics if the typed arrays have different lengths.
+    pub fn add_assign(&self, other: &TypedArray) -> Result<(), KindMismatchError> {
+        self.assign_elementwise(other, Operation::Add, true)
+    }
+
+    /// Subtracts every element of `other` from the corresponding element of
+    /// this typed array. Integers wrap around on overflow, except those of a
+    /// `Uint8ClampedArray`, which saturate.
+    ///
+    /// Returns an error if `other` is of a different kind.
+    ///
+    /// # Panics
+    /// Panics if the typed arrays have different lengths.
+    pub fn sub_assign(&self, other: &TypedArray) -> Result<(), KindMismatchError> {
+        self.assign_elementwise(other, Operation::Sub, true)
+    }
+
+    /// Multiplies every element of this typed array by the corresponding
+    /// element of `other`. Integers wrap around on overflow, except those of a
+    /// `Uint8ClampedArray`, which saturate.
+    ///
+    /// Returns an error if `other` is of a different kind.
+    ///
+    /// # Panics
+    /// Panics if the typed arrays have different lengths.
+    pub fn mul_assign(&self, other: &TypedArray) -> Result<(), KindMismatchError> {
+        self.assign_elementwise(other, Operation::Mul, true)
+    }
+
+    /// The dot product of the values of this typed array and `other`, computed
+    /// in `f64`. The typed arrays may be of different kinds.
+    ///
+    /// # Panics
+    /// Panics if the typed arrays have different lengths.
+    pub fn dot(&self, other: &TypedArray) -> f64 {
+        let length = self.length();
+        assert_eq!(
+            length,
+            other.length(),
+            "typed arrays have different lengths"
+        );
+        let mut dot = 0.0;
+        zip_element_chunks(self, other, length, |_, ours, theirs| {
+            dot += ours.iter().zip(theirs).map(|(a, b)| a * b).sum::<f64>();
+            true
+        });
+        dot
+    }
+
+    /// Replaces every element with the result of `operation` on it and the
+    /// corresponding element of `other`, in chunks. The elements of a
+    /// `Uint8ClampedArray` saturate if `saturate_clamped` is set, and wrap
+    /// around like those of a `Uint8Array` otherwise.
+    pub(crate) fn assign_elementwise(
+        &self,
+        other: &TypedArray,
+        operation: Operation,
+        saturate_clamped: bool,
+    ) -> Result<(), KindMismatchError> {
+        self.check_delta(other)?;
+        let length = self.length();
+        macro_rules! assign {
+            ($(($variant:ident, $apply:expr)),*) => {
+                match (self, other) {
+                    $(
+                        (TypedArray::$variant(array), TypedArray::$variant(other)) => {
+                            for (begin, end) in chunk_ranges(length, CHUNK_LEN) {
+                                let chunk = array.subarray(begin, end);
+                                let mut values = chunk.to_vec();
+                                let others = other.subarray(begin, end).to_vec();
+                                for (value, other) in values.iter_mut().zip(others) {
+                                    *value = $apply(*value, operation, other);
+                                }
+                                chunk.copy_from(&values);
+                            }
+                        }
+                    )*
+                    _ => unreachable!("kinds were checked"),
+                }
+            };
+        }
+        assign!(
+            (Int8Array, Arithmetic::apply),
+            (Uint8Array, Arithmetic::apply),
+            (Uint8ClampedArray, |value: u8, operation, other| {
+                if saturate_clamped {
+                    apply_clamped(value, operation, other)
+                } else {
+                    value.apply(operation, other)
+                }
+            }),
+            (Int16Array, Arithmetic::apply),
+            (Uint16Array, Arithmetic::apply),
+            (Int32Array, Arithmetic::apply),
+            (Uint32Array, Arithmetic::apply),
+            (Float32Array, Arithmetic::apply),
+            (Float64Array, Arithmetic::apply)
+        );
+        Ok(())
+    }
+}
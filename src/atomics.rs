@@ -0,0 +1,229 @@
+//! Atomic operations on the elements of integer typed arrays.
+
+use crate::{TypedArray, TypedArrayKind};
+use err_derive::*;
+use js_sys::{Atomics, Int32Array, Promise, Reflect};
+use wasm_bindgen::{throw_val, JsCast, JsValue};
+
+/// Returned when an atomic operation is attempted on a typed array whose kind
+/// does not support it. Only `Int8Array`, `Uint8Array`, `Int16Array`,
+/// `Uint16Array`, `Int32Array` and `Uint32Array` do.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error(
+    display = "atomic operations are not supported on a typed array of kind {:?}",
+    kind
+)]
+pub struct AtomicKindError {
+    kind: TypedArrayKind,
+}
+
+impl AtomicKindError {
+    /// The kind of the typed array.
+    pub fn kind(&self) -> TypedArrayKind {
+        self.kind
+    }
+}
+
+/// The outcome of waiting on an element with [`atomic_wait`].
+///
+/// [`atomic_wait`]: enum.TypedArray.html#method.atomic_wait
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum AtomicWaitResult {
+    /// The thread was woken up by a notification, `"ok"` in Javascript.
+    Ok,
+    /// The element did not have the expected value, so the thread did not
+    /// sleep, `"not-equal"` in Javascript.
+    NotEqual,
+    /// The timeout expired before a notification arrived, `"timed-out"` in
+    /// Javascript.
+    TimedOut,
+}
+
+impl AtomicWaitResult {
+    /// Converts the string that `Atomics.wait()` returns, or that the promise
+    /// returned by [`atomic_wait_async`] resolves to. Returns `None` for any
+    /// other value.
+    ///
+    /// [`atomic_wait_async`]: enum.TypedArray.html#method.atomic_wait_async
+    pub fn from_js_value(value: &JsValue) -> Option<Self> {
+        match value.as_string()?.as_str() {
+            "ok" => Some(AtomicWaitResult::Ok),
+            "not-equal" => Some(AtomicWaitResult::NotEqual),
+            "timed-out" => Some(AtomicWaitResult::TimedOut),
+            _ => None,
+        }
+    }
+}
+
+/// Calls `$x` with the inner typed array bound to `$name` if the kind supports
+/// atomic operations, rethrowing any Javascript exception.
+macro_rules! match_atomic {
+    ($to_match:expr, $name:ident, $x:expr) => {
+        match $to_match {
+            TypedArray::Int8Array($name) => Ok(rethrow($x)),
+            TypedArray::Uint8Array($name) => Ok(rethrow($x)),
+            TypedArray::Int16Array($name) => Ok(rethrow($x)),
+            TypedArray::Uint16Array($name) => Ok(rethrow($x)),
+            TypedArray::Int32Array($name) => Ok(rethrow($x)),
+            TypedArray::Uint32Array($name) => Ok(rethrow($x)),
+            array => Err(AtomicKindError { kind: array.kind() }),
+        }
+    };
+}
+
+/// Rethrows an exception caught from `Atomics`, the same as a binding without
+/// `catch` would.
+fn rethrow<T>(result: Result<T, JsValue>) -> T {
+    result.unwrap_or_else(|exception| throw_val(exception))
+}
+
+macro_rules! impl_atomic_ops {
+    ($($method:ident: $op:ident, $doc:expr;)*) => {
+        $(
+            #[doc = $doc]
+            /// Returns the value that was at `index` before.
+            ///
+            /// Values are passed as `i32`, and converted to the element type
+            /// the same way Javascript does. Elements of a `Uint32Array` above
+            /// `i32::MAX` are returned wrapped around to negative numbers, which
+            /// `as u32` turns back into the element.
+            ///
+            /// # Panics
+            /// Panics if `index` is out of bounds.
+            pub fn $method(&self, index: u32, value: i32) -> Result<i32, AtomicKindError> {
+                match_atomic!(self, i, Atomics::$op(i, index, value))
+            }
+        )*
+    };
+}
+
+impl TypedArray {
+    /// Atomically reads the element at `index`, with `Atomics.load()`.
+    ///
+    /// Elements of a `Uint32Array` above `i32::MAX` are returned wrapped around
+    /// to negative numbers, which `as u32` turns back into the element.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn atomic_load(&self, index: u32) -> Result<i32, AtomicKindError> {
+        match_atomic!(self, i, Atomics::load(i, index))
+    }
+
+    /// Atomically writes `value` to the element at `index`, with
+    /// `Atomics.store()`. Returns `value`, even if the element type cannot
+    /// represent it.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn atomic_store(&self, index: u32, value: i32) -> Result<i32, AtomicKindError> {
+        match_atomic!(self, i, Atomics::store(i, index, value))
+    }
+
+    impl_atomic_ops! {
+        atomic_add: add, "Atomically adds `value` to the element at `index`, with `Atomics.add()`.";
+        atomic_sub: sub, "Atomically subtracts `value` from the element at `index`, with `Atomics.sub()`.";
+        atomic_and: and, "Atomically computes the bitwise AND of the element at `index` and `value`, with `Atomics.and()`.";
+        atomic_or: or, "Atomically computes the bitwise OR of the element at `index` and `value`, with `Atomics.or()`.";
+        atomic_xor: xor, "Atomically computes the bitwise XOR of the element at `index` and `value`, with `Atomics.xor()`.";
+        atomic_exchange: exchange, "Atomically replaces the element at `index` with `value`, with `Atomics.exchange()`.";
+    }
+
+    /// Atomically replaces the element at `index` with `replacement` if it is
+    /// equal to `expected`, with `Atomics.compareExchange()`. Returns the
+    /// value that was at `index` before, whether it was replaced or not. It is
+    /// converted to an `i32` the same way as the result of [`atomic_load`].
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    ///
+    /// [`atomic_load`]: #method.atomic_load
+    pub fn atomic_compare_exchange(
+        &self,
+        index: u32,
+        expected: i32,
+        replacement: i32,
+    ) -> Result<i32, AtomicKindError> {
+        match_atomic!(
+            self,
+            i,
+            Atomics::compare_exchange(i, index, expected, replacement)
+        )
+    }
+
+    /// Puts the current thread to sleep with `Atomics.wait()` if the element
+    /// at `index` is equal to `expected`, until it is woken up by
+    /// [`atomic_notify`] or `timeout` milliseconds have passed. Without a
+    /// timeout, the thread sleeps until it is notified.
+    ///
+    /// Returns the Javascript exception if this is not an `Int32Array` viewing
+    /// a `SharedArrayBuffer`, if `index` is out of bounds, or if the thread is
+    /// not allowed to block, which is the case for the main thread of a
+    /// browser. Use [`atomic_wait_async`] there instead.
+    ///
+    /// [`atomic_notify`]: #method.atomic_notify
+    /// [`atomic_wait_async`]: #method.atomic_wait_async
+    pub fn atomic_wait(
+        &self,
+        index: u32,
+        expected: i32,
+        timeout: Option<f64>,
+    ) -> Result<AtomicWaitResult, JsValue> {
+        let array = self.as_int32_array();
+        let result = match timeout {
+            Some(timeout) => Atomics::wait_with_timeout(array, index, expected, timeout)?,
+            None => Atomics::wait(array, index, expected)?,
+        };
+        Ok(AtomicWaitResult::from_js_value(&result).expect("unexpected result of Atomics.wait"))
+    }
+
+    /// The same as [`atomic_wait`], but waits asynchronously with
+    /// `Atomics.waitAsync()`, which is also allowed on the main thread. The
+    /// returned promise resolves to the string representation of an
+    /// [`AtomicWaitResult`], which can be converted with
+    /// [`AtomicWaitResult::from_js_value`].
+    ///
+    /// Returns the Javascript exception for the same reasons as
+    /// [`atomic_wait`], or if the engine does not support waiting
+    /// asynchronously.
+    ///
+    /// [`atomic_wait`]: #method.atomic_wait
+    /// [`AtomicWaitResult`]: enum.AtomicWaitResult.html
+    /// [`AtomicWaitResult::from_js_value`]: enum.AtomicWaitResult.html#method.from_js_value
+    pub fn atomic_wait_async(
+        &self,
+        index: u32,
+        expected: i32,
+        timeout: Option<f64>,
+    ) -> Result<Promise, JsValue> {
+        let array = self.as_int32_array();
+        let result = match timeout {
+            Some(timeout) => Atomics::wait_async_with_timeout(array, index, expected, timeout)?,
+            None => Atomics::wait_async(array, index, expected)?,
+        };
+        // The value is a promise if the wait is asynchronous, and the final
+        // result otherwise.
+        let value = Reflect::get(&result, &"value".into())?;
+        Ok(Promise::resolve(&value))
+    }
+
+    /// Wakes up at most `count` threads that are waiting on the element at
+    /// `index`, or all of them if `count` is `None`, with `Atomics.notify()`.
+    /// Returns the number of threads that were woken up, which is always zero
+    /// if the buffer is not shared.
+    ///
+    /// Returns the Javascript exception if this is not an `Int32Array`, or if
+    /// `index` is out of bounds.
+    pub fn atomic_notify(&self, index: u32, count: Option<u32>) -> Result<u32, JsValue> {
+        let array = self.as_int32_array();
+        match count {
+            Some(count) => Atomics::notify_with_count(array, index, count),
+            None => Atomics::notify(array, index),
+        }
+    }
+
+    /// Views this typed array as an `Int32Array` for `Atomics` methods that
+    /// only accept that kind. They throw for any other kind.
+    fn as_int32_array(&self) -> &Int32Array {
+        AsRef::<JsValue>::as_ref(self).unchecked_ref()
+    }
+}
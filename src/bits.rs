@@ -0,0 +1,161 @@
+//! Reading bit-packed data from the bytes of typed arrays.
+
+use crate::TypedArray;
+use err_derive::*;
+use js_sys::Uint8Array;
+
+/// The order in which a [`BitReader`] takes the bits out of each byte.
+///
+/// [`BitReader`]: struct.BitReader.html
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum BitOrder {
+    /// The most significant bit of each byte comes first, and the first bit
+    /// read becomes the most significant bit of the value. This is the order
+    /// of most codec headers, such as those of MPEG and H.264.
+    MsbFirst,
+    /// The least significant bit of each byte comes first, and the first bit
+    /// read becomes the least significant bit of the value. This is the order
+    /// of DEFLATE.
+    LsbFirst,
+}
+
+/// Returned when a [`BitReader`] is asked to read past the end of its typed
+/// array.
+///
+/// [`BitReader`]: struct.BitReader.html
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error(
+    display = "requested {} bits at bit {}, but only {} are available",
+    requested,
+    position,
+    available
+)]
+pub struct EndOfBitsError {
+    position: u64,
+    requested: u32,
+    available: u64,
+}
+
+impl EndOfBitsError {
+    /// The position of the reader, in bits.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// The number of bits that were requested.
+    pub fn requested(&self) -> u32 {
+        self.requested
+    }
+
+    /// The number of bits that were left after the position.
+    pub fn available(&self) -> u64 {
+        self.available
+    }
+}
+
+/// A cursor that reads values of any number of bits sequentially from the
+/// bytes of a `TypedArray`, keeping track of the position in bits.
+///
+/// Reads that do not fit in the remaining bits fail without moving the
+/// position.
+#[derive(Clone, Debug)]
+pub struct BitReader {
+    bytes: Uint8Array,
+    order: BitOrder,
+    position: u64,
+    /// The index and value of the byte that was read last, so that reading a
+    /// byte bit by bit only reads it from Javascript once.
+    current: Option<(u32, u8)>,
+}
+
+impl BitReader {
+    /// Creates a reader positioned at the first bit of `array`, which takes
+    /// the bits out of each byte in the given order.
+    pub fn new(array: &TypedArray, order: BitOrder) -> Self {
+        BitReader {
+            bytes: array.as_bytes(),
+            order,
+            position: 0,
+            current: None,
+        }
+    }
+
+    /// The current position, in bits from the start of the typed array.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// The number of bits after the current position. This is zero if the
+    /// buffer has shrunk to before the position.
+    pub fn remaining(&self) -> u64 {
+        (u64::from(self.bytes.length()) * 8).saturating_sub(self.position)
+    }
+
+    /// Returns `true` if the position is at the start of a byte.
+    pub fn is_aligned(&self) -> bool {
+        self.position % 8 == 0
+    }
+
+    /// Advances the position to the start of the next byte, unless it already
+    /// is at the start of a byte.
+    pub fn align_to_byte(&mut self) {
+        self.position = self.position.div_ceil(8) * 8;
+    }
+
+    /// Reads a single bit.
+    pub fn read_bit(&mut self) -> Result<bool, EndOfBitsError> {
+        self.read_bits(1).map(|bit| bit != 0)
+    }
+
+    /// Reads `n` bits as an unsigned integer, combined according to the
+    /// [`BitOrder`] of this reader. Reading zero bits returns zero.
+    ///
+    /// # Panics
+    /// Panics if `n` is larger than 64.
+    ///
+    /// [`BitOrder`]: enum.BitOrder.html
+    pub fn read_bits(&mut self, n: u32) -> Result<u64, EndOfBitsError> {
+        assert!(n <= 64, "cannot read more than 64 bits at once");
+        let available = self.remaining();
+        if u64::from(n) > available {
+            return Err(EndOfBitsError {
+                position: self.position,
+                requested: n,
+                available,
+            });
+        }
+        let mut value = 0;
+        let mut read = 0;
+        while read < n {
+            let byte = self.byte((self.position / 8) as u32);
+            let offset = (self.position % 8) as u32;
+            let count = (8 - offset).min(n - read);
+            let mask = 0xff >> (8 - count);
+            match self.order {
+                BitOrder::MsbFirst => {
+                    let bits = (byte >> (8 - offset - count)) & mask;
+                    value = value << count | u64::from(bits);
+                }
+                BitOrder::LsbFirst => {
+                    let bits = (byte >> offset) & mask;
+                    value |= u64::from(bits) << read;
+                }
+            }
+            read += count;
+            self.position += u64::from(count);
+        }
+        Ok(value)
+    }
+
+    /// Reads the byte at `index`, or returns it from the cache.
+    fn byte(&mut self, index: u32) -> u8 {
+        match self.current {
+            Some((cached, byte)) if cached == index => byte,
+            _ => {
+                let byte = self.bytes.get_index(index);
+                self.current = Some((index, byte));
+                byte
+            }
+        }
+    }
+}
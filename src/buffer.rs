@@ -0,0 +1,55 @@
+//! The buffers that typed arrays can view.
+
+use js_sys::{ArrayBuffer, SharedArrayBuffer};
+use wasm_bindgen::JsValue;
+
+/// The buffer viewed by a `TypedArray`, which is either an `ArrayBuffer` or a
+/// `SharedArrayBuffer`.
+///
+/// This enum is returned by the [`backing_buffer`] method on `TypedArray`.
+///
+/// [`backing_buffer`]: enum.TypedArray.html#method.backing_buffer
+#[derive(Clone, Debug)]
+pub enum TypedArrayBuffer {
+    ArrayBuffer(ArrayBuffer),
+    SharedArrayBuffer(SharedArrayBuffer),
+}
+
+impl TypedArrayBuffer {
+    /// Returns `true` if this is a `SharedArrayBuffer`.
+    pub fn is_shared(&self) -> bool {
+        match self {
+            TypedArrayBuffer::ArrayBuffer(_) => false,
+            TypedArrayBuffer::SharedArrayBuffer(_) => true,
+        }
+    }
+
+    /// The length of the buffer in bytes.
+    pub fn byte_length(&self) -> u32 {
+        match self {
+            TypedArrayBuffer::ArrayBuffer(buffer) => buffer.byte_length(),
+            TypedArrayBuffer::SharedArrayBuffer(buffer) => buffer.byte_length(),
+        }
+    }
+}
+
+impl From<ArrayBuffer> for TypedArrayBuffer {
+    fn from(buffer: ArrayBuffer) -> Self {
+        TypedArrayBuffer::ArrayBuffer(buffer)
+    }
+}
+
+impl From<SharedArrayBuffer> for TypedArrayBuffer {
+    fn from(buffer: SharedArrayBuffer) -> Self {
+        TypedArrayBuffer::SharedArrayBuffer(buffer)
+    }
+}
+
+impl AsRef<JsValue> for TypedArrayBuffer {
+    fn as_ref(&self) -> &JsValue {
+        match self {
+            TypedArrayBuffer::ArrayBuffer(buffer) => buffer.as_ref(),
+            TypedArrayBuffer::SharedArrayBuffer(buffer) => buffer.as_ref(),
+        }
+    }
+}
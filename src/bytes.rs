@@ -0,0 +1,59 @@
+//! Serializing typed arrays of bytes as plain bytes, in the same format as
+//! `serde_bytes`.
+//!
+//! Use this module with `#[serde(with = "typed_array::bytes")]` on a field of
+//! type `TypedArray`. The field is then compatible with a `Vec<u8>` or
+//! `serde_bytes::ByteBuf` field annotated with `#[serde(with = "serde_bytes")]`
+//! on the other side. Only typed arrays with one byte per element can be
+//! serialized, and deserializing always creates a `Uint8Array`.
+//!
+//! This module requires the `serde` feature.
+
+use crate::{serialize, TypedArray, TypedArrayKind};
+use core::fmt;
+use serde::de::{self, Deserializer, SeqAccess, Visitor};
+use serde::ser::{self, Serializer};
+
+/// Serializes the bytes of `array` with `serialize_bytes`. Returns an error
+/// if the elements of `array` are larger than a byte.
+pub fn serialize<S: Serializer>(array: &TypedArray, serializer: S) -> Result<S::Ok, S::Error> {
+    if array.kind().bytes_per_element() != 1 {
+        return Err(ser::Error::custom(format!(
+            "cannot serialize a typed array of kind {:?} as bytes",
+            array.kind()
+        )));
+    }
+    serializer.serialize_bytes(&array.as_bytes().to_vec())
+}
+
+/// Deserializes bytes, or a sequence of integers, into a new `Uint8Array`.
+/// Borrowed bytes are copied into the typed array directly.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TypedArray, D::Error> {
+    deserializer.deserialize_byte_buf(Uint8ArrayVisitor)
+}
+
+struct Uint8ArrayVisitor;
+
+impl<'de> Visitor<'de> for Uint8ArrayVisitor {
+    type Value = TypedArray;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("bytes")
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<TypedArray, E> {
+        serialize::from_bytes(TypedArrayKind::Uint8Array, bytes)
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, bytes: Vec<u8>) -> Result<TypedArray, E> {
+        self.visit_bytes(&bytes)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<TypedArray, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        self.visit_bytes(&bytes)
+    }
+}
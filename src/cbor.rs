@@ -0,0 +1,109 @@
+//! Encoding typed arrays as CBOR, with the typed array tags of RFC 8746.
+
+use crate::wire::{self, DecodeError};
+use crate::{TypedArray, TypedArrayKind};
+use core::convert::TryFrom;
+
+/// The major type of a tag.
+const TAG: u8 = 6;
+
+/// The major type of a byte string.
+const BYTE_STRING: u8 = 2;
+
+impl TypedArray {
+    /// Encodes this typed array as a CBOR byte string with the typed array tag
+    /// of RFC 8746 for its kind, so that peers in other languages can decode
+    /// the elements with the right type. The elements are written in little
+    /// endian byte order.
+    ///
+    /// This method requires the `cbor` feature.
+    pub fn to_cbor(&self) -> Vec<u8> {
+        let bytes = self.as_bytes().to_vec();
+        let mut cbor = Vec::with_capacity(bytes.len() + 11);
+        write_head(&mut cbor, TAG, u64::from(wire::cbor_tag(self.kind())));
+        write_head(&mut cbor, BYTE_STRING, bytes.len() as u64);
+        cbor.extend_from_slice(&bytes);
+        cbor
+    }
+
+    /// Decodes a typed array encoded as a CBOR byte string with a typed array
+    /// tag of RFC 8746. Both byte orders are supported, but tags for element
+    /// types without a `TypedArray` kind, such as 64-bit integers, are not.
+    /// An untagged byte string is decoded as a `Uint8Array`.
+    ///
+    /// This method requires the `cbor` feature.
+    ///
+    /// Returns an error if the typed array would exceed the limit set with
+    /// [`set_allocation_limit`].
+    ///
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    pub fn from_cbor(cbor: &[u8]) -> Result<Self, DecodeError> {
+        let mut position = 0;
+        let (mut major, mut argument) = read_head(cbor, &mut position)?;
+        let mut kind = (TypedArrayKind::Uint8Array, false);
+        let mut head = 0;
+        if major == TAG {
+            kind = wire::kind_of_cbor_tag(argument).ok_or_else(|| DecodeError::new(0))?;
+            head = position;
+            let (next_major, next_argument) = read_head(cbor, &mut position)?;
+            major = next_major;
+            argument = next_argument;
+        }
+        if major != BYTE_STRING {
+            return Err(DecodeError::new(head));
+        }
+        let bytes = usize::try_from(argument)
+            .ok()
+            .and_then(|length| cbor.get(position..)?.get(..length))
+            .ok_or_else(|| DecodeError::new(position))?;
+        if position + bytes.len() != cbor.len() {
+            return Err(DecodeError::new(position + bytes.len()));
+        }
+        wire::from_element_bytes(kind.0, bytes, kind.1, position)
+    }
+}
+
+/// Writes the head of a data item, with the shortest encoding of `argument`.
+fn write_head(cbor: &mut Vec<u8>, major: u8, argument: u64) {
+    let major = major << 5;
+    if argument < 24 {
+        cbor.push(major | argument as u8);
+    } else if let Ok(argument) = u8::try_from(argument) {
+        cbor.push(major | 24);
+        cbor.push(argument);
+    } else if let Ok(argument) = u16::try_from(argument) {
+        cbor.push(major | 25);
+        cbor.extend_from_slice(&argument.to_be_bytes());
+    } else if let Ok(argument) = u32::try_from(argument) {
+        cbor.push(major | 26);
+        cbor.extend_from_slice(&argument.to_be_bytes());
+    } else {
+        cbor.push(major | 27);
+        cbor.extend_from_slice(&argument.to_be_bytes());
+    }
+}
+
+/// Reads the head of a data item at `position`, returning its major type and
+/// argument. Indefinite lengths are not supported.
+fn read_head(cbor: &[u8], position: &mut usize) -> Result<(u8, u64), DecodeError> {
+    let start = *position;
+    let error = || DecodeError::new(start);
+    let initial = *cbor.get(start).ok_or_else(error)?;
+    let size = match initial & 0x1f {
+        info @ 0..=23 => {
+            *position += 1;
+            return Ok((initial >> 5, u64::from(info)));
+        }
+        24 => 1,
+        25 => 2,
+        26 => 4,
+        27 => 8,
+        _ => return Err(error()),
+    };
+    let bytes = cbor.get(start + 1..start + 1 + size).ok_or_else(error)?;
+    *position += 1 + size;
+    let argument = bytes
+        .iter()
+        .fold(0, |argument, &byte| argument << 8 | u64::from(byte));
+    Ok((initial >> 5, argument))
+}
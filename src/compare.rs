@@ -0,0 +1,380 @@
+//! Comparing the contents of typed arrays.
+
+use crate::{chunk_ranges, TypedArray, CHUNK_LEN};
+use core::cmp::Ordering;
+use core::hash::{Hash, Hasher};
+use core::ops::{Deref, Range};
+use js_sys::Uint8Array;
+
+/// A typed array that is compared and hashed by its contents, so that it can
+/// be used as a key in a `HashMap` or `HashSet`.
+///
+/// Two `HashableTypedArray`s are equal if [`content_eq`] returns `true`, and
+/// are hashed with [`hash_contents`]. The contents must not be changed while
+/// the typed array is used as a key.
+///
+/// [`content_eq`]: enum.TypedArray.html#method.content_eq
+/// [`hash_contents`]: enum.TypedArray.html#method.hash_contents
+#[derive(Clone, Debug)]
+pub struct HashableTypedArray {
+    array: TypedArray,
+}
+
+impl HashableTypedArray {
+    /// Wraps a typed array to compare and hash it by its contents.
+    pub fn new(array: TypedArray) -> Self {
+        HashableTypedArray { array }
+    }
+
+    /// Returns the wrapped typed array.
+    pub fn into_inner(self) -> TypedArray {
+        self.array
+    }
+}
+
+impl From<TypedArray> for HashableTypedArray {
+    fn from(array: TypedArray) -> Self {
+        HashableTypedArray::new(array)
+    }
+}
+
+impl Deref for HashableTypedArray {
+    type Target = TypedArray;
+
+    fn deref(&self) -> &TypedArray {
+        &self.array
+    }
+}
+
+impl PartialEq for HashableTypedArray {
+    fn eq(&self, other: &Self) -> bool {
+        self.array.content_eq(&other.array)
+    }
+}
+
+impl Eq for HashableTypedArray {}
+
+impl Hash for HashableTypedArray {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.array.hash_contents(state);
+    }
+}
+
+/// A summary of the differences between two typed arrays, returned by
+/// [`diff`] and [`diff_with_ranges`].
+///
+/// [`diff`]: enum.TypedArray.html#method.diff
+/// [`diff_with_ranges`]: enum.TypedArray.html#method.diff_with_ranges
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+pub struct DiffReport {
+    first_mismatch: Option<u32>,
+    mismatches: u32,
+    ranges: Option<Vec<Range<u32>>>,
+}
+
+impl DiffReport {
+    /// Returns `true` if the typed arrays have the same length and values.
+    pub fn is_equal(&self) -> bool {
+        self.mismatches == 0
+    }
+
+    /// The index of the first element that differs, or `None` if the typed
+    /// arrays are equal.
+    pub fn first_mismatch(&self) -> Option<u32> {
+        self.first_mismatch
+    }
+
+    /// The number of elements that differ, including the elements past the
+    /// end of the shorter typed array.
+    pub fn mismatches(&self) -> u32 {
+        self.mismatches
+    }
+
+    /// The ranges of consecutive indices at which the elements differ, in
+    /// ascending order. This is `None` unless the report was created by
+    /// [`diff_with_ranges`].
+    ///
+    /// [`diff_with_ranges`]: enum.TypedArray.html#method.diff_with_ranges
+    pub fn ranges(&self) -> Option<&[Range<u32>]> {
+        self.ranges.as_deref()
+    }
+}
+
+impl TypedArray {
+    /// Compares the values of this typed array and `other` element by element,
+    /// and summarizes where they differ. The typed arrays may be of different
+    /// kinds. A `NaN` is considered equal to any other `NaN`, and `0.0` to
+    /// `-0.0`. If the lengths differ, the elements past the end of the shorter
+    /// typed array count as mismatches.
+    ///
+    /// Use [`diff_with_ranges`] to also collect the ranges of the mismatches.
+    ///
+    /// [`diff_with_ranges`]: #method.diff_with_ranges
+    pub fn diff(&self, other: &TypedArray) -> DiffReport {
+        let mut report = DiffReport {
+            first_mismatch: None,
+            mismatches: 0,
+            ranges: None,
+        };
+        self.diff_into(other, &mut report, |_| {});
+        report
+    }
+
+    /// The same as [`diff`], but also collects the ranges of consecutive
+    /// indices at which the elements differ.
+    ///
+    /// [`diff`]: #method.diff
+    pub fn diff_with_ranges(&self, other: &TypedArray) -> DiffReport {
+        let mut ranges: Vec<Range<u32>> = Vec::new();
+        let mut report = DiffReport {
+            first_mismatch: None,
+            mismatches: 0,
+            ranges: None,
+        };
+        self.diff_into(other, &mut report, |index| match ranges.last_mut() {
+            Some(range) if range.end == index => range.end += 1,
+            _ => ranges.push(index..index + 1),
+        });
+        report.ranges = Some(ranges);
+        report
+    }
+
+    /// Counts the mismatches between this typed array and `other` into
+    /// `report`, and calls `mismatch` with the index of every mismatch.
+    fn diff_into(
+        &self,
+        other: &TypedArray,
+        report: &mut DiffReport,
+        mut mismatch: impl FnMut(u32),
+    ) {
+        let length = self.length();
+        let other_length = other.length();
+        let mut record = |index: u32| {
+            report.first_mismatch.get_or_insert(index);
+            report.mismatches += 1;
+            mismatch(index);
+        };
+        zip_element_chunks(
+            self,
+            other,
+            length.min(other_length),
+            |begin, ours, theirs| {
+                for (index, (&a, &b)) in (begin..).zip(ours.iter().zip(theirs)) {
+                    if a != b && !(a.is_nan() && b.is_nan()) {
+                        record(index);
+                    }
+                }
+                true
+            },
+        );
+        for index in length.min(other_length)..length.max(other_length) {
+            record(index);
+        }
+    }
+
+    /// Returns `true` if this typed array and `other` have the same length, and
+    /// every pair of elements differs by at most `epsilon`. This is meant for
+    /// floating point results, which are rarely exactly equal to the expected
+    /// values, but the typed arrays may be of any kind.
+    ///
+    /// Elements that are equal, including equal infinities, always match, as
+    /// do two `NaN`s.
+    pub fn approx_eq(&self, other: &TypedArray, epsilon: f64) -> bool {
+        self.approx_eq_by(other, |a, b| (a - b).abs() <= epsilon)
+    }
+
+    /// The same as [`approx_eq`], but `epsilon` is relative to the magnitude
+    /// of the elements: every pair of elements `a` and `b` must differ by at
+    /// most `epsilon * max(|a|, |b|)`.
+    ///
+    /// [`approx_eq`]: #method.approx_eq
+    pub fn approx_eq_relative(&self, other: &TypedArray, epsilon: f64) -> bool {
+        self.approx_eq_by(other, |a, b| {
+            (a - b).abs() <= epsilon * a.abs().max(b.abs())
+        })
+    }
+
+    fn approx_eq_by(&self, other: &TypedArray, close: impl Fn(f64, f64) -> bool) -> bool {
+        let length = self.length();
+        length == other.length()
+            && zip_element_chunks(self, other, length, |_, ours, theirs| {
+                ours.iter()
+                    .zip(theirs)
+                    .all(|(&a, &b)| a == b || (a.is_nan() && b.is_nan()) || close(a, b))
+            })
+    }
+
+    /// Returns `true` if this typed array and `other` are of the same kind and
+    /// contain the same bytes. Unlike comparing the handles, this compares the
+    /// contents, which are copied into Rust in chunks.
+    ///
+    /// Because the bytes are compared, a floating point `NaN` is equal to a
+    /// `NaN` with the same bits, and `0.0` is not equal to `-0.0`.
+    pub fn content_eq(&self, other: &TypedArray) -> bool {
+        if self.kind() != other.kind() || self.length() != other.length() {
+            return false;
+        }
+        if self.byte_offset() == other.byte_offset() && self.same_buffer_as(other) {
+            return true;
+        }
+        zip_byte_chunks(&self.as_bytes(), &other.as_bytes(), |a, b| a == b)
+    }
+
+    /// Feeds the kind and the bytes of this typed array into `state`. Typed
+    /// arrays for which [`content_eq`] returns `true` hash to the same value.
+    ///
+    /// [`content_eq`]: #method.content_eq
+    pub fn hash_contents<H: Hasher>(&self, state: &mut H) {
+        self.hash_chunks(CHUNK_LEN, state);
+    }
+
+    /// The same as [`hash_contents`], but copies at most `chunk_len` bytes
+    /// into Rust at a time, to bound the memory used for large typed arrays.
+    ///
+    /// The bytes are passed to `state` in separate calls to `write`, so the
+    /// result is the same as that of [`hash_contents`] for hashers that do
+    /// not depend on how the bytes are split up, such as the `DefaultHasher`
+    /// of the standard library.
+    ///
+    /// # Panics
+    /// Panics if `chunk_len` is zero.
+    ///
+    /// [`hash_contents`]: #method.hash_contents
+    pub fn hash_chunks<H: Hasher>(&self, chunk_len: u32, state: &mut H) {
+        self.kind().hash(state);
+        self.length().hash(state);
+        self.digest_streaming(chunk_len, |chunk| state.write(chunk));
+    }
+
+    /// Passes the bytes of this typed array to `update`, in consecutive
+    /// chunks of at most `chunk_len` bytes. Only one chunk is copied into Rust
+    /// at a time, so this can feed large typed arrays into a digest such as a
+    /// cryptographic hash function with bounded memory.
+    ///
+    /// # Panics
+    /// Panics if `chunk_len` is zero.
+    pub fn digest_streaming(&self, chunk_len: u32, mut update: impl FnMut(&[u8])) {
+        assert!(chunk_len > 0, "chunk length must be greater than zero");
+        let bytes = self.as_bytes();
+        let mut chunk = Vec::new();
+        for (begin, end) in chunk_ranges(bytes.length(), chunk_len) {
+            chunk.resize((end - begin) as usize, 0);
+            bytes.subarray(begin, end).copy_to(&mut chunk);
+            update(&chunk);
+        }
+    }
+
+    /// Compares the elements of this typed array and `other` lexicographically,
+    /// like slices are compared. Returns `None` if the typed arrays are of
+    /// different kinds.
+    ///
+    /// Floating point elements are compared using the total order of IEEE
+    /// 754, so that any two typed arrays of the same kind can be ordered. In
+    /// this order `-0.0` is smaller than `0.0`, and `NaN` is larger than every
+    /// number if its sign is positive, and smaller if it is negative.
+    pub fn cmp_contents(&self, other: &TypedArray) -> Option<Ordering> {
+        if self.kind() != other.kind() {
+            return None;
+        }
+        let ordering = match (self, other) {
+            (TypedArray::Float32Array(a), TypedArray::Float32Array(b)) => cmp_elements(
+                [a.length(), b.length()],
+                |begin, end| {
+                    [
+                        a.subarray(begin, end).to_vec(),
+                        b.subarray(begin, end).to_vec(),
+                    ]
+                },
+                f32::total_cmp,
+            ),
+            (TypedArray::Float64Array(a), TypedArray::Float64Array(b)) => cmp_elements(
+                [a.length(), b.length()],
+                |begin, end| {
+                    [
+                        a.subarray(begin, end).to_vec(),
+                        b.subarray(begin, end).to_vec(),
+                    ]
+                },
+                f64::total_cmp,
+            ),
+            // Integers are converted to `f64` exactly.
+            _ => cmp_elements(
+                [self.length(), other.length()],
+                |begin, end| {
+                    let mut ours = Vec::new();
+                    let mut theirs = Vec::new();
+                    self.copy_range_into(begin, end, &mut ours);
+                    other.copy_range_into(begin, end, &mut theirs);
+                    [ours, theirs]
+                },
+                f64::total_cmp,
+            ),
+        };
+        Some(ordering)
+    }
+}
+
+/// Compares two sequences of elements of the given lengths lexicographically.
+/// `copy` copies the elements in a range of both sequences, which is never
+/// longer than the shortest sequence.
+fn cmp_elements<T>(
+    lengths: [u32; 2],
+    mut copy: impl FnMut(u32, u32) -> [Vec<T>; 2],
+    cmp: impl Fn(&T, &T) -> Ordering,
+) -> Ordering {
+    for (begin, end) in chunk_ranges(lengths[0].min(lengths[1]), CHUNK_LEN) {
+        let [ours, theirs] = copy(begin, end);
+        for (a, b) in ours.iter().zip(&theirs) {
+            match cmp(a, b) {
+                Ordering::Equal => {}
+                ordering => return ordering,
+            }
+        }
+    }
+    lengths[0].cmp(&lengths[1])
+}
+
+/// Copies the first `length` elements of `a` and `b` into Rust as `f64` in
+/// chunks, and calls `f` with the index of the first element of every pair of
+/// chunks until it returns `false`. Returns whether `f` returned `true` for
+/// all chunks.
+pub(crate) fn zip_element_chunks(
+    a: &TypedArray,
+    b: &TypedArray,
+    length: u32,
+    mut f: impl FnMut(u32, &[f64], &[f64]) -> bool,
+) -> bool {
+    let mut ours = Vec::new();
+    let mut theirs = Vec::new();
+    for (begin, end) in chunk_ranges(length, CHUNK_LEN) {
+        a.copy_range_into(begin, end, &mut ours);
+        b.copy_range_into(begin, end, &mut theirs);
+        if !f(begin, &ours, &theirs) {
+            return false;
+        }
+    }
+    true
+}
+
+/// Copies the bytes of `a` and `b`, which must have the same length, into
+/// Rust in chunks and calls `f` with every pair of chunks until it returns
+/// `false`. Returns whether `f` returned `true` for all chunks.
+pub(crate) fn zip_byte_chunks(
+    a: &Uint8Array,
+    b: &Uint8Array,
+    mut f: impl FnMut(&[u8], &[u8]) -> bool,
+) -> bool {
+    let mut ours = Vec::new();
+    let mut theirs = Vec::new();
+    for (begin, end) in chunk_ranges(a.length(), CHUNK_LEN) {
+        let n = (end - begin) as usize;
+        ours.resize(n, 0);
+        theirs.resize(n, 0);
+        a.subarray(begin, end).copy_to(&mut ours);
+        b.subarray(begin, end).copy_to(&mut theirs);
+        if !f(&ours, &theirs) {
+            return false;
+        }
+    }
+    true
+}
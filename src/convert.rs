@@ -0,0 +1,122 @@
+//! Converting the values of a typed array to another kind.
+
+use crate::{AllocationLimitError, TypedArray, TypedArrayKind};
+use js_sys::Float64Array;
+
+/// How values are converted by [`TypedArray::convert_to`] when they cannot be
+/// represented exactly by the target kind.
+///
+/// Values stored in a floating point kind are always rounded to the nearest
+/// value of that precision; the modes mostly determine what happens for
+/// integer kinds.
+///
+/// [`TypedArray::convert_to`]: enum.TypedArray.html#method.convert_to
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum ConversionMode {
+    /// Drops the fractional part and wraps values that are out of range, the
+    /// same as Javascript does when assigning to an element. A
+    /// `Uint8ClampedArray` clamps instead of wrapping.
+    Truncate,
+    /// Rounds to the nearest integer, with halfway cases rounded away from
+    /// zero, and wraps values that are out of range like `Truncate`.
+    Round,
+    /// Rounds to the nearest integer and clamps values that are out of range
+    /// to the nearest representable one. NaN becomes zero. For a
+    /// `Float32Array`, finite values are clamped to the finite range of `f32`
+    /// rather than overflowing to infinity.
+    Saturate,
+    /// Maps the full range of integer kinds to `0.0..=1.0` for unsigned
+    /// kinds, or `-1.0..=1.0` for signed kinds, and back. Floating point
+    /// values are clamped to that range before being scaled to an integer
+    /// kind. Converting between two integer kinds scales from one range to
+    /// the other, and converting between floating point kinds does not
+    /// change the values.
+    Normalize,
+}
+
+impl TypedArray {
+    /// Creates a new typed array of the given kind, containing the elements
+    /// of this typed array converted according to `mode`. The values are
+    /// converted, not the bytes; see [`reinterpret_as`] for the latter.
+    ///
+    /// # Panics
+    /// Panics if the typed array would exceed the limit set with
+    /// [`set_allocation_limit`]. Use [`try_convert_to`] to handle this.
+    ///
+    /// [`reinterpret_as`]: #method.reinterpret_as
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    /// [`try_convert_to`]: #method.try_convert_to
+    pub fn convert_to(&self, kind: TypedArrayKind, mode: ConversionMode) -> Self {
+        self.try_convert_to(kind, mode)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// The same as [`convert_to`], but returns an error if the typed array
+    /// would exceed the limit set with [`set_allocation_limit`].
+    ///
+    /// [`convert_to`]: #method.convert_to
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    pub fn try_convert_to(
+        &self,
+        kind: TypedArrayKind,
+        mode: ConversionMode,
+    ) -> Result<Self, AllocationLimitError> {
+        let result = TypedArray::try_new_with_length(kind, self.length())?;
+        let source = self.kind().integer_range();
+        let target = kind.integer_range();
+        let mut values = Vec::new();
+        self.copy_range_into(0, self.length(), &mut values);
+        for value in &mut values {
+            *value = convert(*value, source, target, kind, mode);
+        }
+        result.set(&Float64Array::from(&values[..]), 0);
+        Ok(result)
+    }
+}
+
+/// Converts a single value from a kind with integer range `source` to one
+/// with integer range `target`, where `None` stands for a floating point kind.
+fn convert(
+    value: f64,
+    source: Option<(f64, f64)>,
+    target: Option<(f64, f64)>,
+    kind: TypedArrayKind,
+    mode: ConversionMode,
+) -> f64 {
+    match (mode, target) {
+        (ConversionMode::Truncate, Some(_)) => value.trunc(),
+        (ConversionMode::Round, Some(_)) => value.round(),
+        (ConversionMode::Saturate, Some(_)) | (ConversionMode::Normalize, Some(_))
+            if value.is_nan() =>
+        {
+            0.0
+        }
+        (ConversionMode::Saturate, Some((min, max))) => value.round().max(min).min(max),
+        (ConversionMode::Saturate, None)
+            if kind == TypedArrayKind::Float32Array && value.is_finite() =>
+        {
+            value.max(f64::from(f32::MIN)).min(f64::from(f32::MAX))
+        }
+        (ConversionMode::Normalize, _) => {
+            let normalized = match source {
+                Some((min, max)) => (value / max).max(lower_bound(min)),
+                None => value,
+            };
+            match target {
+                Some((min, max)) => (normalized.max(lower_bound(min)).min(1.0) * max).round(),
+                None => normalized,
+            }
+        }
+        (_, _) => value,
+    }
+}
+
+/// The lower end of the normalized range for an integer kind with smallest
+/// value `min`.
+fn lower_bound(min: f64) -> f64 {
+    if min < 0.0 {
+        -1.0
+    } else {
+        0.0
+    }
+}
@@ -0,0 +1,250 @@
+//! Cursors for reading and writing binary data sequentially.
+
+use crate::varint::{self, VarintError, MAX_VARINT_LEN};
+use crate::TypedArray;
+use core::convert::TryFrom;
+use err_derive::*;
+use js_sys::{DataView, Uint8Array};
+
+/// Returned when a cursor is asked to read, write or skip past the end of its
+/// typed array.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error(
+    display = "requested {} bytes at position {}, but only {} are available",
+    requested,
+    position,
+    available
+)]
+pub struct EndOfBufferError {
+    position: u32,
+    requested: u32,
+    available: u32,
+}
+
+impl EndOfBufferError {
+    /// The position of the cursor, in bytes.
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+
+    /// The number of bytes that were requested.
+    pub fn requested(&self) -> u32 {
+        self.requested
+    }
+
+    /// The number of bytes that were left after the position.
+    pub fn available(&self) -> u32 {
+        self.available
+    }
+}
+
+macro_rules! impl_reads {
+    ($($read:ident: $ty:ident, $size:expr, $get:ident, $little_endian:expr;)*) => {
+        $(
+            #[doc = concat!("Reads a `", stringify!($ty), "` and advances the position past it. See")]
+            #[doc = concat!("[`TypedArray::", stringify!($read), "`](enum.TypedArray.html#method.", stringify!($read), ").")]
+            pub fn $read(&mut self) -> Result<$ty, EndOfBufferError> {
+                let offset = self.advance($size)?;
+                Ok(self.view.$get(offset as usize, $little_endian))
+            }
+        )*
+    };
+}
+
+/// A cursor that reads binary data sequentially from the bytes of a
+/// `TypedArray`, keeping track of the position.
+#[derive(Clone, Debug)]
+pub struct TypedArrayReader {
+    bytes: Uint8Array,
+    view: DataView,
+    position: u32,
+}
+
+impl TypedArrayReader {
+    /// Creates a reader positioned at the first byte of `array`.
+    pub fn new(array: &TypedArray) -> Self {
+        TypedArrayReader {
+            bytes: array.as_bytes(),
+            view: array.data_view(),
+            position: 0,
+        }
+    }
+
+    /// The current position, in bytes from the start of the typed array.
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+
+    /// The number of bytes after the current position. This is zero if the
+    /// buffer has shrunk to before the position.
+    pub fn remaining(&self) -> u32 {
+        self.bytes.length().saturating_sub(self.position)
+    }
+
+    /// Advances the position by `n` bytes without reading them.
+    pub fn skip(&mut self, n: u32) -> Result<(), EndOfBufferError> {
+        self.advance(n).map(|_| ())
+    }
+
+    /// Reads a single byte.
+    pub fn read_u8(&mut self) -> Result<u8, EndOfBufferError> {
+        let offset = self.advance(1)?;
+        Ok(self.bytes.get_index(offset))
+    }
+
+    /// Reads `n` bytes, returned as a view that shares the buffer of the typed
+    /// array.
+    pub fn read_bytes(&mut self, n: u32) -> Result<Uint8Array, EndOfBufferError> {
+        let offset = self.advance(n)?;
+        Ok(self.bytes.subarray(offset, offset + n))
+    }
+
+    /// Reads an unsigned LEB128 varint. See
+    /// [`TypedArray::read_varint_at`](enum.TypedArray.html#method.read_varint_at).
+    ///
+    /// The position is not moved if the varint is invalid or truncated.
+    pub fn read_varint(&mut self) -> Result<u64, VarintError> {
+        let end = self.position + self.remaining().min(MAX_VARINT_LEN);
+        let bytes = self.bytes.subarray(self.position, end).to_vec();
+        let (value, len) = varint::decode_varint(&bytes).ok_or(VarintError::new(self.position))?;
+        self.position += len;
+        Ok(value)
+    }
+
+    /// Reads a signed varint in the zigzag encoding. See
+    /// [`TypedArray::read_zigzag_varint_at`](enum.TypedArray.html#method.read_zigzag_varint_at).
+    ///
+    /// The position is not moved if the varint is invalid or truncated.
+    pub fn read_zigzag_varint(&mut self) -> Result<i64, VarintError> {
+        self.read_varint().map(varint::zigzag_decode)
+    }
+
+    impl_reads! {
+        read_u16_le: u16, 2, get_uint16_endian, true;
+        read_u16_be: u16, 2, get_uint16_endian, false;
+        read_u32_le: u32, 4, get_uint32_endian, true;
+        read_u32_be: u32, 4, get_uint32_endian, false;
+        read_f32_le: f32, 4, get_float32_endian, true;
+        read_f32_be: f32, 4, get_float32_endian, false;
+        read_f64_le: f64, 8, get_float64_endian, true;
+        read_f64_be: f64, 8, get_float64_endian, false;
+    }
+
+    fn advance(&mut self, n: u32) -> Result<u32, EndOfBufferError> {
+        let available = self.remaining();
+        advance(&mut self.position, available, n)
+    }
+}
+
+macro_rules! impl_writes {
+    ($($write:ident: $ty:ident, $size:expr, $set:ident, $little_endian:expr;)*) => {
+        $(
+            #[doc = concat!("Writes a `", stringify!($ty), "` and advances the position past it. See")]
+            #[doc = concat!("[`TypedArray::", stringify!($write), "`](enum.TypedArray.html#method.", stringify!($write), ").")]
+            pub fn $write(&mut self, value: $ty) -> Result<(), EndOfBufferError> {
+                let offset = self.advance($size)?;
+                self.view.$set(offset as usize, value, $little_endian);
+                Ok(())
+            }
+        )*
+    };
+}
+
+/// A cursor that writes binary data sequentially into the bytes of an existing
+/// `TypedArray`, keeping track of the position.
+///
+/// Writes that do not fit in the remaining bytes fail without writing
+/// anything or moving the position.
+#[derive(Clone, Debug)]
+pub struct TypedArrayWriter {
+    bytes: Uint8Array,
+    view: DataView,
+    position: u32,
+}
+
+impl TypedArrayWriter {
+    /// Creates a writer positioned at the first byte of `array`.
+    pub fn new(array: &TypedArray) -> Self {
+        TypedArrayWriter {
+            bytes: array.as_bytes(),
+            view: array.data_view(),
+            position: 0,
+        }
+    }
+
+    /// The current position, in bytes from the start of the typed array.
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+
+    /// The number of bytes after the current position. This is zero if the
+    /// buffer has shrunk to before the position.
+    pub fn remaining(&self) -> u32 {
+        self.bytes.length().saturating_sub(self.position)
+    }
+
+    /// Advances the position by `n` bytes, leaving them unchanged.
+    pub fn skip(&mut self, n: u32) -> Result<(), EndOfBufferError> {
+        self.advance(n).map(|_| ())
+    }
+
+    /// Writes a single byte.
+    pub fn write_u8(&mut self, value: u8) -> Result<(), EndOfBufferError> {
+        let offset = self.advance(1)?;
+        self.bytes.set_index(offset, value);
+        Ok(())
+    }
+
+    /// Writes all of `bytes`.
+    pub fn write_bytes(&mut self, bytes: &[u8]) -> Result<(), EndOfBufferError> {
+        let n = u32::try_from(bytes.len()).unwrap_or(u32::MAX);
+        let offset = self.advance(n)?;
+        self.bytes.subarray(offset, offset + n).copy_from(bytes);
+        Ok(())
+    }
+
+    /// Writes an unsigned LEB128 varint. See
+    /// [`TypedArray::write_varint_at`](enum.TypedArray.html#method.write_varint_at).
+    pub fn write_varint(&mut self, value: u64) -> Result<(), EndOfBufferError> {
+        let (encoded, len) = varint::encode_varint(value);
+        self.write_bytes(&encoded[..len as usize])
+    }
+
+    /// Writes a signed varint in the zigzag encoding. See
+    /// [`TypedArray::write_zigzag_varint_at`](enum.TypedArray.html#method.write_zigzag_varint_at).
+    pub fn write_zigzag_varint(&mut self, value: i64) -> Result<(), EndOfBufferError> {
+        self.write_varint(varint::zigzag_encode(value))
+    }
+
+    impl_writes! {
+        write_u16_le: u16, 2, set_uint16_endian, true;
+        write_u16_be: u16, 2, set_uint16_endian, false;
+        write_u32_le: u32, 4, set_uint32_endian, true;
+        write_u32_be: u32, 4, set_uint32_endian, false;
+        write_f32_le: f32, 4, set_float32_endian, true;
+        write_f32_be: f32, 4, set_float32_endian, false;
+        write_f64_le: f64, 8, set_float64_endian, true;
+        write_f64_be: f64, 8, set_float64_endian, false;
+    }
+
+    fn advance(&mut self, n: u32) -> Result<u32, EndOfBufferError> {
+        let available = self.remaining();
+        advance(&mut self.position, available, n)
+    }
+}
+
+/// Moves `position` `n` bytes forward if at least that many bytes are
+/// `available` after it, returning the old position.
+fn advance(position: &mut u32, available: u32, n: u32) -> Result<u32, EndOfBufferError> {
+    if n <= available {
+        let offset = *position;
+        *position += n;
+        Ok(offset)
+    } else {
+        Err(EndOfBufferError {
+            position: *position,
+            requested: n,
+            available,
+        })
+    }
+}
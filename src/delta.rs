@@ -0,0 +1,62 @@
+//! Patching typed arrays in place with the contents of another typed array.
+
+use crate::arith::Operation;
+use crate::{chunk_ranges, KindMismatchError, TypedArray, CHUNK_LEN};
+
+impl TypedArray {
+    /// Replaces every byte of this typed array with the exclusive or of it
+    /// and the corresponding byte of `other`. Applying the same `other` twice
+    /// restores the original contents, so the exclusive or of an old and a
+    /// new state can be used as a patch between them.
+    ///
+    /// Returns an error if `other` is of a different kind.
+    ///
+    /// # Panics
+    /// Panics if the typed arrays have different lengths.
+    pub fn xor_with(&self, other: &TypedArray) -> Result<(), KindMismatchError> {
+        self.check_delta(other)?;
+        let ours = self.as_bytes();
+        let theirs = other.as_bytes();
+        for (begin, end) in chunk_ranges(ours.length(), CHUNK_LEN) {
+            let chunk = ours.subarray(begin, end);
+            let mut bytes = chunk.to_vec();
+            let deltas = theirs.subarray(begin, end).to_vec();
+            for (byte, delta) in bytes.iter_mut().zip(deltas) {
+                *byte ^= delta;
+            }
+            chunk.copy_from(&bytes);
+        }
+        Ok(())
+    }
+
+    /// Adds every element of `delta` to the corresponding element of this
+    /// typed array. Integers wrap around on overflow, so a delta computed by
+    /// subtracting an old state from a new one with wrapping arithmetic turns
+    /// the old state into the new one. This includes `Uint8ClampedArray`,
+    /// whose elements are not clamped.
+    ///
+    /// Returns an error if `delta` is of a different kind.
+    ///
+    /// # Panics
+    /// Panics if the typed arrays have different lengths.
+    pub fn apply_delta(&self, delta: &TypedArray) -> Result<(), KindMismatchError> {
+        self.assign_elementwise(delta, Operation::Add, false)
+    }
+
+    /// Checks that `other` can be used to patch this typed array, or as the
+    /// other operand of an elementwise operation.
+    pub(crate) fn check_delta(&self, other: &TypedArray) -> Result<(), KindMismatchError> {
+        if self.kind() != other.kind() {
+            return Err(KindMismatchError {
+                expected: self.kind(),
+                found: other.kind(),
+            });
+        }
+        assert_eq!(
+            self.length(),
+            other.length(),
+            "typed arrays have different lengths"
+        );
+        Ok(())
+    }
+}
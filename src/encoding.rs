@@ -0,0 +1,289 @@
+//! Converting between the bytes of typed arrays and strings.
+
+use crate::sys::TextDecoder;
+use crate::{AllocationLimitError, TypedArray, TypedArrayKind};
+use core::convert::TryFrom;
+use core::ops::RangeBounds;
+use err_derive::*;
+use js_sys::{Object, Reflect, SharedArrayBuffer, Uint8Array};
+use wasm_bindgen::{throw_val, JsCast, JsValue};
+
+/// Returned by [`decode_utf16`] when the code units are not valid UTF-16,
+/// because a surrogate is not part of a pair or the last unit is incomplete.
+///
+/// [`decode_utf16`]: enum.TypedArray.html#method.decode_utf16
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error(display = "invalid UTF-16 code unit at index {}", index)]
+pub struct Utf16Error {
+    index: u32,
+}
+
+impl Utf16Error {
+    /// The index of the code unit that is not valid.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+}
+
+impl TypedArray {
+    /// Creates a `Uint8Array` with the UTF-8 encoding of `string`. The result
+    /// is the same as that of `TextEncoder.prototype.encode`, but the bytes
+    /// are copied directly, without converting `string` to a Javascript string
+    /// first.
+    ///
+    /// # Panics
+    /// Panics if the typed array would exceed the limit set with
+    /// [`set_allocation_limit`]. Use [`try_from_str_utf8`] to handle this.
+    ///
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    /// [`try_from_str_utf8`]: #method.try_from_str_utf8
+    pub fn from_str_utf8(string: &str) -> Self {
+        TypedArray::try_from_str_utf8(string).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// The same as [`from_str_utf8`], but returns an error if the typed array
+    /// would exceed the limit set with [`set_allocation_limit`].
+    ///
+    /// # Panics
+    /// Panics if `string` is longer than `u32::MAX` bytes.
+    ///
+    /// [`from_str_utf8`]: #method.from_str_utf8
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    pub fn try_from_str_utf8(string: &str) -> Result<Self, AllocationLimitError> {
+        let length = u32::try_from(string.len()).expect("string is too long for a typed array");
+        let array = TypedArray::try_new_with_length(TypedArrayKind::Uint8Array, length)?;
+        array.as_bytes().copy_from(string.as_bytes());
+        Ok(array)
+    }
+
+    /// Writes the UTF-8 encoding of `string` to the start of the bytes of this
+    /// typed array, and returns the number of bytes written. Like
+    /// `TextEncoder.prototype.encodeInto`, only whole characters are written,
+    /// so if `string` does not fit, the rest of it is `&string[written..]`.
+    pub fn encode_into_existing(&self, string: &str) -> u32 {
+        let bytes = self.as_bytes();
+        let mut written = string.len().min(bytes.length() as usize);
+        while !string.is_char_boundary(written) {
+            written -= 1;
+        }
+        bytes
+            .subarray(0, written as u32)
+            .copy_from(&string.as_bytes()[..written]);
+        written as u32
+    }
+
+    /// Decodes the bytes of this typed array as UTF-8, using a `TextDecoder`.
+    /// A byte order mark at the start is removed. Returns the `TypeError`
+    /// thrown by the decoder if the bytes are not valid UTF-8.
+    pub fn decode_utf8(&self) -> Result<String, JsValue> {
+        decode("utf-8", &self.as_bytes(), true)
+    }
+
+    /// The same as [`decode_utf8`], but replaces invalid sequences with
+    /// U+FFFD REPLACEMENT CHARACTER instead of failing.
+    ///
+    /// [`decode_utf8`]: #method.decode_utf8
+    pub fn decode_utf8_lossy(&self) -> String {
+        decode("utf-8", &self.as_bytes(), false).unwrap_or_else(|exception| throw_val(exception))
+    }
+
+    /// Decodes UTF-16, checking that every surrogate is part of a pair. For a
+    /// `Uint16Array`, the elements are the code units. For other kinds, the
+    /// bytes are read in pairs in the byte order of the platform, which is
+    /// little endian for WebAssembly, so a `Uint8Array` holds UTF-16LE.
+    pub fn decode_utf16(&self) -> Result<String, Utf16Error> {
+        let units = self.utf16_units();
+        let mut decoded = String::with_capacity(units.len());
+        let mut index = 0;
+        for c in char::decode_utf16(units.iter().copied()) {
+            let c = c.map_err(|_| Utf16Error { index })?;
+            decoded.push(c);
+            index += c.len_utf16() as u32;
+        }
+        if self.byte_length() % 2 != 0 {
+            return Err(Utf16Error { index });
+        }
+        Ok(decoded)
+    }
+
+    /// The same as [`decode_utf16`], but replaces unpaired surrogates and an
+    /// incomplete last code unit with U+FFFD REPLACEMENT CHARACTER instead of
+    /// failing.
+    ///
+    /// [`decode_utf16`]: #method.decode_utf16
+    pub fn decode_utf16_lossy(&self) -> String {
+        let mut decoded: String = char::decode_utf16(self.utf16_units())
+            .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+            .collect();
+        if self.byte_length() % 2 != 0 {
+            decoded.push(char::REPLACEMENT_CHARACTER);
+        }
+        decoded
+    }
+
+    /// The complete UTF-16 code units in the bytes of this typed array.
+    fn utf16_units(&self) -> Vec<u16> {
+        self.as_bytes()
+            .to_vec()
+            .chunks_exact(2)
+            .map(|unit| u16::from_ne_bytes([unit[0], unit[1]]))
+            .collect()
+    }
+
+    /// The same as [`decode_utf8`], but only decodes the bytes in
+    /// `byte_range`, relative to the start of this typed array. Unbounded ends
+    /// are resolved against the byte length.
+    ///
+    /// # Panics
+    /// Panics if the range is not within the bytes of this typed array.
+    ///
+    /// [`decode_utf8`]: #method.decode_utf8
+    pub fn decode_utf8_range(&self, byte_range: impl RangeBounds<u32>) -> Result<String, JsValue> {
+        let bytes = self.as_bytes_typed_array();
+        let (begin, end) = bytes.resolve_range(byte_range);
+        assert!(
+            begin <= end && end <= bytes.length(),
+            "byte range out of bounds"
+        );
+        decode("utf-8", &self.as_bytes().subarray(begin, end), true)
+    }
+
+    /// Decodes the bytes of this typed array as ISO-8859-1, in which every
+    /// byte is the code point of the same value.
+    ///
+    /// This is done in Rust, because the `latin1` label of `TextDecoder`
+    /// actually selects Windows-1252, which differs for the bytes `0x80` to
+    /// `0x9f`. Use [`decode_windows1252`] for text that uses those bytes for
+    /// printable characters.
+    ///
+    /// [`decode_windows1252`]: #method.decode_windows1252
+    pub fn decode_latin1(&self) -> String {
+        self.as_bytes()
+            .to_vec()
+            .into_iter()
+            .map(char::from)
+            .collect()
+    }
+
+    /// Decodes the bytes of this typed array as Windows-1252, using a
+    /// `TextDecoder`. If the engine does not support the encoding, the bytes
+    /// are decoded in Rust instead, with the same result.
+    pub fn decode_windows1252(&self) -> String {
+        decode("windows-1252", &self.as_bytes(), false).unwrap_or_else(|_| {
+            self.as_bytes()
+                .to_vec()
+                .into_iter()
+                .map(windows1252_char)
+                .collect()
+        })
+    }
+}
+
+/// The characters of the bytes `0x80` to `0x9f` in Windows-1252, as defined by
+/// the Encoding Standard. The other bytes are the same as in ISO-8859-1.
+const WINDOWS_1252_HIGH: [char; 32] = [
+    '\u{20ac}', '\u{81}', '\u{201a}', '\u{192}', '\u{201e}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{2c6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8d}', '\u{17d}', '\u{8f}',
+    '\u{90}', '\u{2018}', '\u{2019}', '\u{201c}', '\u{201d}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{2dc}', '\u{2122}', '\u{161}', '\u{203a}', '\u{153}', '\u{9d}', '\u{17e}', '\u{178}',
+];
+
+/// The character of a byte in Windows-1252.
+fn windows1252_char(byte: u8) -> char {
+    match byte {
+        0x80..=0x9f => WINDOWS_1252_HIGH[usize::from(byte - 0x80)],
+        _ => char::from(byte),
+    }
+}
+
+/// Decodes UTF-8 from typed arrays that arrive in chunks, such as the bodies
+/// of streamed `fetch` responses or WebSocket messages.
+///
+/// A character that is split between two chunks is returned with the chunk
+/// that completes it, using the `stream` option of `TextDecoder`. After the
+/// last chunk, [`finish`] returns whatever the decoder still holds.
+///
+/// [`finish`]: #method.finish
+#[derive(Debug)]
+pub struct StreamingDecoder {
+    decoder: TextDecoder,
+}
+
+impl StreamingDecoder {
+    /// Creates a decoder that fails on invalid UTF-8, like [`decode_utf8`].
+    ///
+    /// [`decode_utf8`]: enum.TypedArray.html#method.decode_utf8
+    pub fn new() -> Self {
+        StreamingDecoder {
+            decoder: new_decoder("utf-8", true).expect("UTF-8 is always supported"),
+        }
+    }
+
+    /// Creates a decoder that replaces invalid sequences with U+FFFD
+    /// REPLACEMENT CHARACTER, like [`decode_utf8_lossy`].
+    ///
+    /// [`decode_utf8_lossy`]: enum.TypedArray.html#method.decode_utf8_lossy
+    pub fn new_lossy() -> Self {
+        StreamingDecoder {
+            decoder: new_decoder("utf-8", false).expect("UTF-8 is always supported"),
+        }
+    }
+
+    /// Decodes the bytes of the next chunk, and returns the characters that
+    /// are complete. Returns the `TypeError` thrown by the decoder if the
+    /// bytes are not valid UTF-8, after which the decoder starts over as if
+    /// it were new.
+    pub fn decode(&mut self, chunk: &TypedArray) -> Result<String, JsValue> {
+        let options = Object::new();
+        Reflect::set(&options, &"stream".into(), &true.into())
+            .expect("setting a property of a new object cannot fail");
+        decode_with(&self.decoder, &chunk.as_bytes(), &options)
+    }
+
+    /// Ends the stream, and returns the characters that were not yet
+    /// returned. Fails if the last chunk ended in the middle of a character
+    /// and the decoder is not lossy. The decoder can be used again for a new
+    /// stream afterwards.
+    pub fn finish(&mut self) -> Result<String, JsValue> {
+        decode_with(
+            &self.decoder,
+            &Uint8Array::new_with_length(0),
+            &JsValue::UNDEFINED,
+        )
+    }
+}
+
+impl Default for StreamingDecoder {
+    fn default() -> Self {
+        StreamingDecoder::new()
+    }
+}
+
+/// Decodes `bytes` with a `TextDecoder` for the encoding `label`. A decoder
+/// that is `fatal` fails on invalid input instead of inserting replacement
+/// characters.
+pub(crate) fn decode(label: &str, bytes: &Uint8Array, fatal: bool) -> Result<String, JsValue> {
+    decode_with(&new_decoder(label, fatal)?, bytes, &JsValue::UNDEFINED)
+}
+
+/// Creates a `TextDecoder` for the encoding `label`.
+fn new_decoder(label: &str, fatal: bool) -> Result<TextDecoder, JsValue> {
+    let options = Object::new();
+    Reflect::set(&options, &"fatal".into(), &fatal.into())
+        .expect("setting a property of a new object cannot fail");
+    TextDecoder::new(label, &options)
+}
+
+/// Decodes `bytes` with `decoder`, passing `options` to `decode`.
+fn decode_with(
+    decoder: &TextDecoder,
+    bytes: &Uint8Array,
+    options: &JsValue,
+) -> Result<String, JsValue> {
+    // Decoders reject views of shared memory, so those are copied first.
+    if bytes.buffer().has_type::<SharedArrayBuffer>() {
+        decoder.decode(&bytes.slice(0, bytes.length()), options)
+    } else {
+        decoder.decode(bytes, options)
+    }
+}
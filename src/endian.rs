@@ -0,0 +1,85 @@
+//! Reading and writing scalars with an explicit byte order.
+
+use crate::TypedArray;
+
+macro_rules! impl_endian {
+    ($(
+        $ty:ident, $size:expr, $get:ident, $set:ident,
+        $read_le:ident, $read_be:ident, $write_le:ident, $write_be:ident;
+    )*) => {
+        impl TypedArray {
+            $(
+                #[doc = concat!("Reads a little-endian `", stringify!($ty), "` at `byte_offset` bytes")]
+                /// from the start of this typed array.
+                ///
+                /// # Panics
+                /// Panics if the value is not within the bytes of this typed
+                /// array.
+                pub fn $read_le(&self, byte_offset: u32) -> $ty {
+                    self.data_view_range(byte_offset, $size).$get(0, true)
+                }
+
+                #[doc = concat!("Reads a big-endian `", stringify!($ty), "` at `byte_offset` bytes")]
+                /// from the start of this typed array.
+                ///
+                /// # Panics
+                /// Panics if the value is not within the bytes of this typed
+                /// array.
+                pub fn $read_be(&self, byte_offset: u32) -> $ty {
+                    self.data_view_range(byte_offset, $size).$get(0, false)
+                }
+
+                #[doc = concat!("Writes a little-endian `", stringify!($ty), "` at `byte_offset` bytes")]
+                /// from the start of this typed array.
+                ///
+                /// # Panics
+                /// Panics if the value is not within the bytes of this typed
+                /// array.
+                pub fn $write_le(&self, byte_offset: u32, value: $ty) {
+                    self.data_view_range(byte_offset, $size).$set(0, value, true)
+                }
+
+                #[doc = concat!("Writes a big-endian `", stringify!($ty), "` at `byte_offset` bytes")]
+                /// from the start of this typed array.
+                ///
+                /// # Panics
+                /// Panics if the value is not within the bytes of this typed
+                /// array.
+                pub fn $write_be(&self, byte_offset: u32, value: $ty) {
+                    self.data_view_range(byte_offset, $size).$set(0, value, false)
+                }
+            )*
+        }
+    };
+}
+
+impl_endian! {
+    u16, 2, get_uint16_endian, set_uint16_endian,
+        read_u16_le, read_u16_be, write_u16_le, write_u16_be;
+    u32, 4, get_uint32_endian, set_uint32_endian,
+        read_u32_le, read_u32_be, write_u32_le, write_u32_be;
+    f32, 4, get_float32_endian, set_float32_endian,
+        read_f32_le, read_f32_be, write_f32_le, write_f32_be;
+    f64, 8, get_float64_endian, set_float64_endian,
+        read_f64_le, read_f64_be, write_f64_le, write_f64_be;
+}
+
+impl TypedArray {
+    /// Reverses the byte order of every element in place, converting between
+    /// little-endian and big-endian data. Does nothing for arrays with 8-bit
+    /// elements.
+    ///
+    /// The bytes are copied out once, swapped, and copied back in.
+    pub fn swap_bytes(&self) {
+        let size = self.bytes_per_element() as usize;
+        if size == 1 {
+            return;
+        }
+        let view = self.as_bytes();
+        let mut bytes = view.to_vec();
+        for element in bytes.chunks_exact_mut(size) {
+            element.reverse();
+        }
+        view.copy_from(&bytes);
+    }
+}
@@ -0,0 +1,153 @@
+//! Formatting typed arrays.
+
+use crate::{chunk_ranges, TypedArray, CHUNK_LEN};
+use core::fmt;
+
+/// The number of elements that the `Debug` implementation of `TypedArray`
+/// shows.
+const DEBUG_PREVIEW_LEN: u32 = 8;
+
+impl fmt::Display for TypedArray {
+    /// Formats the elements separated by commas, like the `toString()`
+    /// method in Javascript.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.join(","))
+    }
+}
+
+impl fmt::Debug for TypedArray {
+    /// Formats the kind, the length and the first and last few elements, like
+    /// `Float32Array(len=4096) [0.0, 0.5, 0.25, 0.125, …, 1.0, 1.0, 1.0, 1.0]`.
+    /// Use [`debug_preview`] to show a different number of elements.
+    ///
+    /// [`debug_preview`]: #method.debug_preview
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.debug_preview(DEBUG_PREVIEW_LEN).fmt(f)
+    }
+}
+
+impl fmt::LowerHex for TypedArray {
+    /// Formats every element as lowercase hexadecimal, separated by spaces,
+    /// in the same way as `UpperHex`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_hex(f, false)
+    }
+}
+
+impl fmt::UpperHex for TypedArray {
+    /// Formats every element as uppercase hexadecimal, separated by spaces.
+    /// Every element is padded with zeros to the number of digits of its
+    /// kind, so a `Uint8Array` is formatted as a byte dump like `00 7F FF`.
+    /// Negative integers are formatted as their two's complement, and
+    /// floating point numbers as their bits. The alternate flag `#` prefixes
+    /// every element with `0x`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_hex(f, true)
+    }
+}
+
+impl TypedArray {
+    fn fmt_hex(&self, f: &mut fmt::Formatter<'_>, upper: bool) -> fmt::Result {
+        let width = 2 * self.kind().bytes_per_element() as usize;
+        let length = self.length();
+        match_every!(self, i, {
+            for (begin, end) in chunk_ranges(length, CHUNK_LEN) {
+                for (index, x) in (begin..).zip(i.subarray(begin, end).to_vec()) {
+                    if index > 0 {
+                        f.write_str(" ")?;
+                    }
+                    if f.alternate() {
+                        f.write_str("0x")?;
+                    }
+                    if upper {
+                        write!(f, "{:01$X}", x.bits(), width)?;
+                    } else {
+                        write!(f, "{:01$x}", x.bits(), width)?;
+                    }
+                }
+            }
+        });
+        Ok(())
+    }
+
+    /// Returns a value whose `Debug` implementation formats the kind and the
+    /// length of this typed array, and at most `max_elements` of its
+    /// elements. If the typed array is longer, the first and last elements
+    /// are shown, separated by `…`.
+    pub fn debug_preview(&self, max_elements: u32) -> DebugPreview<'_> {
+        DebugPreview {
+            array: self,
+            max_elements,
+        }
+    }
+}
+
+/// Formats a preview of the elements of a typed array, returned by
+/// [`debug_preview`].
+///
+/// [`debug_preview`]: enum.TypedArray.html#method.debug_preview
+#[derive(Copy, Clone)]
+pub struct DebugPreview<'a> {
+    array: &'a TypedArray,
+    max_elements: u32,
+}
+
+impl fmt::Debug for DebugPreview<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let length = self.array.length();
+        write!(f, "{:?}(len={}) ", self.array.kind(), length)?;
+        let mut list = f.debug_list();
+        if length <= self.max_elements {
+            match_every!(self.array, i, list.entries(i.to_vec()));
+        } else {
+            let head = self.max_elements.div_ceil(2);
+            let tail = self.max_elements / 2;
+            match_every!(self.array, i, {
+                list.entries(i.subarray(0, head).to_vec());
+                list.entry(&Ellipsis);
+                list.entries(i.subarray(length - tail, length).to_vec());
+            });
+        }
+        list.finish()
+    }
+}
+
+/// Formats as `…` to show that elements were left out.
+struct Ellipsis;
+
+impl fmt::Debug for Ellipsis {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("…")
+    }
+}
+
+/// The bits of an element, for hexadecimal formatting.
+trait Bits {
+    fn bits(self) -> u64;
+}
+
+macro_rules! impl_bits {
+    ($($ty:ident as $unsigned:ident),*) => {
+        $(
+            impl Bits for $ty {
+                fn bits(self) -> u64 {
+                    u64::from(self as $unsigned)
+                }
+            }
+        )*
+    };
+}
+
+impl_bits!(i8 as u8, u8 as u8, i16 as u16, u16 as u16, i32 as u32, u32 as u32);
+
+impl Bits for f32 {
+    fn bits(self) -> u64 {
+        u64::from(self.to_bits())
+    }
+}
+
+impl Bits for f64 {
+    fn bits(self) -> u64 {
+        self.to_bits()
+    }
+}
@@ -0,0 +1,137 @@
+//! Reading and writing elements at arbitrary indices.
+
+use crate::{AllocationLimitError, KindMismatchError, TypedArray};
+use err_derive::*;
+use js_sys::{
+    Float32Array, Float64Array, Int16Array, Int32Array, Int8Array, Uint16Array, Uint32Array,
+    Uint8Array, Uint8ClampedArray,
+};
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Returned when an index array refers to an element past the end of a typed
+/// array.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error(
+    display = "index {} is out of bounds for a typed array of length {}",
+    index,
+    length
+)]
+pub struct IndexError {
+    index: u32,
+    length: u32,
+}
+
+impl IndexError {
+    /// The index that is out of bounds.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// The length of the typed array at the time of the request.
+    pub fn length(&self) -> u32 {
+        self.length
+    }
+}
+
+/// Returned by [`gather`] when the typed array cannot be created.
+///
+/// [`gather`]: enum.TypedArray.html#method.gather
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+pub enum GatherError {
+    /// An index is past the end of the typed array.
+    #[error(display = "{}", _0)]
+    OutOfBounds(#[error(cause)] IndexError),
+    /// The new typed array would exceed the limit set with
+    /// [`set_allocation_limit`].
+    ///
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    #[error(display = "{}", _0)]
+    AllocationLimit(#[error(cause)] AllocationLimitError),
+}
+
+/// Returned by [`scatter`] when the values cannot be written.
+///
+/// [`scatter`]: enum.TypedArray.html#method.scatter
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+pub enum ScatterError {
+    /// The values are of a different kind than the typed array.
+    #[error(display = "{}", _0)]
+    KindMismatch(#[error(cause)] KindMismatchError),
+    /// An index is past the end of the typed array.
+    #[error(display = "{}", _0)]
+    OutOfBounds(#[error(cause)] IndexError),
+}
+
+impl TypedArray {
+    /// Creates a new typed array of the same kind, whose elements are the
+    /// elements of this typed array at `indices`, in the same order. Indices
+    /// may repeat. The elements and indices are copied into WebAssembly memory
+    /// once, instead of reading every element from Javascript.
+    ///
+    /// Returns an error for the first index that is out of bounds, or if the
+    /// typed array would exceed the limit set with [`set_allocation_limit`].
+    ///
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    pub fn gather(&self, indices: &Uint32Array) -> Result<Self, GatherError> {
+        let indices = indices.to_vec();
+        let length = self.length();
+        if let Some(&index) = indices.iter().find(|&&index| index >= length) {
+            return Err(GatherError::OutOfBounds(IndexError { index, length }));
+        }
+        let result = TypedArray::try_new_with_length(self.kind(), indices.len() as u32)
+            .map_err(GatherError::AllocationLimit)?;
+        match_every!(self, array: Array, {
+            let elements = array.to_vec();
+            let values: Vec<_> = indices
+                .iter()
+                .map(|&index| elements[index as usize])
+                .collect();
+            AsRef::<JsValue>::as_ref(&result)
+                .unchecked_ref::<Array>()
+                .copy_from(&values);
+        });
+        Ok(result)
+    }
+
+    /// Writes every element of `values` to the element of this typed array at
+    /// the corresponding index in `indices`, the reverse of [`gather`]. If an
+    /// index repeats, the last value written to it remains. The typed array
+    /// is copied into WebAssembly memory, updated, and copied back.
+    ///
+    /// Returns an error if `values` is of a different kind, or for the first
+    /// index that is out of bounds. Nothing is written in that case.
+    ///
+    /// # Panics
+    /// Panics if `indices` and `values` have different lengths.
+    ///
+    /// [`gather`]: #method.gather
+    pub fn scatter(&self, indices: &Uint32Array, values: &TypedArray) -> Result<(), ScatterError> {
+        if self.kind() != values.kind() {
+            return Err(ScatterError::KindMismatch(KindMismatchError {
+                expected: self.kind(),
+                found: values.kind(),
+            }));
+        }
+        assert_eq!(
+            indices.length(),
+            values.length(),
+            "indices and values have different lengths"
+        );
+        let indices = indices.to_vec();
+        let length = self.length();
+        if let Some(&index) = indices.iter().find(|&&index| index >= length) {
+            return Err(ScatterError::OutOfBounds(IndexError { index, length }));
+        }
+        match_every!(self, array: Array, {
+            let mut elements = array.to_vec();
+            let values = AsRef::<JsValue>::as_ref(values)
+                .unchecked_ref::<Array>()
+                .to_vec();
+            for (&index, value) in indices.iter().zip(values) {
+                elements[index as usize] = value;
+            }
+            array.copy_from(&elements);
+        });
+        Ok(())
+    }
+}
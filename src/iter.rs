@@ -0,0 +1,237 @@
+//! Iterators over the elements of a `TypedArray`.
+
+use crate::TypedArray;
+use core::iter::FusedIterator;
+
+/// An iterator over the elements of a `TypedArray`, converted to `f64`. The
+/// length is read once when the iterator is created, after which elements can
+/// be taken from both ends. See [`snapshot_length`] for what happens if the
+/// buffer is resized during iteration.
+///
+/// This struct is created by the [`iter`] method on `TypedArray`.
+///
+/// [`snapshot_length`]: enum.TypedArray.html#method.snapshot_length
+/// [`iter`]: enum.TypedArray.html#method.iter
+#[derive(Clone, Debug)]
+pub struct Iter<'a> {
+    array: &'a TypedArray,
+    index: u32,
+    end: u32,
+}
+
+impl<'a> Iter<'a> {
+    pub(crate) fn new(array: &'a TypedArray) -> Self {
+        Iter {
+            array,
+            index: 0,
+            end: array.length(),
+        }
+    }
+}
+
+impl<'a> Iterator for Iter<'a> {
+    type Item = f64;
+
+    fn next(&mut self) -> Option<f64> {
+        if self.index < self.end {
+            let value = self.array.get_index(self.index);
+            self.index += 1;
+            Some(value)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = (self.end - self.index) as usize;
+        (len, Some(len))
+    }
+
+    fn nth(&mut self, n: usize) -> Option<f64> {
+        let remaining = (self.end - self.index) as usize;
+        if n < remaining {
+            self.index += n as u32;
+        } else {
+            self.index = self.end;
+        }
+        self.next()
+    }
+}
+
+impl<'a> DoubleEndedIterator for Iter<'a> {
+    fn next_back(&mut self) -> Option<f64> {
+        if self.index < self.end {
+            self.end -= 1;
+            Some(self.array.get_index(self.end))
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a> ExactSizeIterator for Iter<'a> {}
+
+impl<'a> FusedIterator for Iter<'a> {}
+
+impl<'a> IntoIterator for &'a TypedArray {
+    type Item = f64;
+    type IntoIter = Iter<'a>;
+
+    fn into_iter(self) -> Iter<'a> {
+        self.iter()
+    }
+}
+
+/// Chunks of the elements of a `TypedArray`, converted to `f64`.
+///
+/// Every chunk is copied from Javascript at once into a buffer that is reused
+/// for the next chunk, which is much faster than reading elements one by one
+/// for large typed arrays. Because the chunks borrow that buffer, this type
+/// can't implement `Iterator`; use [`next_chunk`] in a `while let` loop
+/// instead.
+///
+/// If the typed array shrinks during iteration because its buffer is resized,
+/// the iteration ends with the last element that is still available.
+///
+/// This struct is created by the [`iter_chunks`] method on `TypedArray`.
+///
+/// [`next_chunk`]: #method.next_chunk
+/// [`iter_chunks`]: enum.TypedArray.html#method.iter_chunks
+#[derive(Clone, Debug)]
+pub struct IterChunks<'a> {
+    array: &'a TypedArray,
+    index: u32,
+    end: u32,
+    chunk_len: u32,
+    buffer: Vec<f64>,
+}
+
+impl<'a> IterChunks<'a> {
+    pub(crate) fn new(array: &'a TypedArray, chunk_len: u32) -> Self {
+        assert!(chunk_len != 0, "chunk length must be non-zero");
+        IterChunks {
+            array,
+            index: 0,
+            end: array.length(),
+            chunk_len,
+            buffer: Vec::with_capacity(chunk_len as usize),
+        }
+    }
+
+    /// Copies the next chunk of at most `chunk_len` elements, or returns
+    /// `None` if all elements have been visited.
+    pub fn next_chunk(&mut self) -> Option<&[f64]> {
+        if self.index < self.end {
+            let begin = self.index;
+            self.index = begin.saturating_add(self.chunk_len).min(self.end);
+            self.array
+                .copy_range_into(begin, self.index, &mut self.buffer);
+            let copied = self.buffer.len() as u32;
+            if copied < self.index - begin {
+                self.index = begin + copied;
+                self.end = self.index;
+            }
+            Some(&self.buffer)
+        } else {
+            None
+        }
+    }
+}
+
+/// An iterator over non-overlapping views of a `TypedArray`. The last view is
+/// shorter if the length is not a multiple of the chunk length.
+///
+/// This struct is created by the [`chunks`] method on `TypedArray`.
+///
+/// [`chunks`]: enum.TypedArray.html#method.chunks
+#[derive(Clone, Debug)]
+pub struct Chunks<'a> {
+    array: &'a TypedArray,
+    index: u32,
+    end: u32,
+    chunk_len: u32,
+}
+
+impl<'a> Chunks<'a> {
+    pub(crate) fn new(array: &'a TypedArray, chunk_len: u32) -> Self {
+        assert!(chunk_len != 0, "chunk length must be non-zero");
+        Chunks {
+            array,
+            index: 0,
+            end: array.length(),
+            chunk_len,
+        }
+    }
+}
+
+impl<'a> Iterator for Chunks<'a> {
+    type Item = TypedArray;
+
+    fn next(&mut self) -> Option<TypedArray> {
+        if self.index < self.end {
+            let begin = self.index;
+            self.index = begin.saturating_add(self.chunk_len).min(self.end);
+            Some(self.array.subarray(begin, self.index))
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.index;
+        let len = remaining.div_ceil(self.chunk_len) as usize;
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for Chunks<'a> {}
+
+impl<'a> FusedIterator for Chunks<'a> {}
+
+/// An iterator over overlapping views of `size` elements of a `TypedArray`.
+///
+/// This struct is created by the [`windows`] method on `TypedArray`.
+///
+/// [`windows`]: enum.TypedArray.html#method.windows
+#[derive(Clone, Debug)]
+pub struct Windows<'a> {
+    array: &'a TypedArray,
+    index: u32,
+    end: u32,
+    size: u32,
+}
+
+impl<'a> Windows<'a> {
+    pub(crate) fn new(array: &'a TypedArray, size: u32) -> Self {
+        assert!(size != 0, "window size must be non-zero");
+        Windows {
+            array,
+            index: 0,
+            end: array.length(),
+            size,
+        }
+    }
+}
+
+impl<'a> Iterator for Windows<'a> {
+    type Item = TypedArray;
+
+    fn next(&mut self) -> Option<TypedArray> {
+        if self.size <= self.end - self.index {
+            let begin = self.index;
+            self.index += 1;
+            Some(self.array.subarray(begin, begin + self.size))
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = ((self.end - self.index) as usize + 1).saturating_sub(self.size as usize);
+        (len, Some(len))
+    }
+}
+
+impl<'a> ExactSizeIterator for Windows<'a> {}
+
+impl<'a> FusedIterator for Windows<'a> {}
@@ -0,0 +1,284 @@
+//! Converting typed arrays to and from JSON arrays of numbers.
+
+use crate::{AllocationLimitError, TypedArray, TypedArrayKind, CHUNK_LEN};
+use core::convert::TryFrom;
+use err_derive::*;
+use js_sys::Float64Array;
+
+/// How [`to_json_string`] and [`from_json_str`] represent `NaN` and the
+/// infinities, for which JSON has no numbers.
+///
+/// [`to_json_string`]: enum.TypedArray.html#method.to_json_string
+/// [`from_json_str`]: enum.TypedArray.html#method.from_json_str
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum NonFiniteMode {
+    /// Writes `null`, the same as `JSON.stringify`. Reading `null` results in
+    /// `NaN`, so infinities do not survive a round trip.
+    Null,
+    /// Writes the strings `"NaN"`, `"Infinity"` and `"-Infinity"`, and reads
+    /// them back as the same values.
+    String,
+    /// Writing a value that is not finite returns an error, and reading
+    /// anything other than a number fails.
+    Error,
+}
+
+/// Returned by [`to_json_string`] when an element is not finite and the mode
+/// is `NonFiniteMode::Error`.
+///
+/// [`to_json_string`]: enum.TypedArray.html#method.to_json_string
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error(display = "element {} is not a finite number", index)]
+pub struct NonFiniteError {
+    index: u32,
+}
+
+impl NonFiniteError {
+    /// The index of the element that is not finite.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+}
+
+/// Returned by [`from_json_str`] when the input is not a JSON array of
+/// numbers, or when the typed array would exceed the limit set with
+/// [`set_allocation_limit`].
+///
+/// [`from_json_str`]: enum.TypedArray.html#method.from_json_str
+/// [`set_allocation_limit`]: fn.set_allocation_limit.html
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error(display = "invalid JSON number array at byte {}", position)]
+pub struct JsonError {
+    position: usize,
+    allocation_limit_error: Option<AllocationLimitError>,
+}
+
+impl JsonError {
+    fn new(position: usize) -> Self {
+        JsonError {
+            position,
+            allocation_limit_error: None,
+        }
+    }
+
+    /// The offset in bytes in the input at which the error was found.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The reason the typed array was not created, if the input is valid but
+    /// the typed array would exceed the allocation limit.
+    pub fn allocation_limit_error(&self) -> Option<AllocationLimitError> {
+        self.allocation_limit_error
+    }
+}
+
+impl TypedArray {
+    /// Writes the elements as a JSON array of numbers, like `[1,0.5,-2]`.
+    /// Numbers are written in the shortest form that reads back as the same
+    /// value of the element type, and `mode` determines how `NaN` and the
+    /// infinities are written.
+    pub fn to_json_string(&self, mode: NonFiniteMode) -> Result<String, NonFiniteError> {
+        let single = self.kind() == TypedArrayKind::Float32Array;
+        let mut json = String::from("[");
+        let mut index = 0;
+        let mut chunks = self.iter_chunks(CHUNK_LEN);
+        while let Some(chunk) = chunks.next_chunk() {
+            for &x in chunk {
+                if index > 0 {
+                    json.push(',');
+                }
+                if x.is_finite() {
+                    write_number(&mut json, x, single);
+                } else {
+                    match mode {
+                        NonFiniteMode::Null => json.push_str("null"),
+                        NonFiniteMode::String if x.is_nan() => json.push_str("\"NaN\""),
+                        NonFiniteMode::String if x > 0.0 => json.push_str("\"Infinity\""),
+                        NonFiniteMode::String => json.push_str("\"-Infinity\""),
+                        NonFiniteMode::Error => return Err(NonFiniteError { index }),
+                    }
+                }
+                index += 1;
+            }
+        }
+        json.push(']');
+        Ok(json)
+    }
+
+    /// Reads a JSON array of numbers into a new typed array of the given kind.
+    /// The numbers are converted to the element type in the same way as when
+    /// assigning an element in Javascript, and `mode` determines which other
+    /// values are accepted for `NaN` and the infinities.
+    ///
+    /// Returns an error if the typed array would exceed the limit set with
+    /// [`set_allocation_limit`].
+    ///
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    pub fn from_json_str(
+        kind: TypedArrayKind,
+        json: &str,
+        mode: NonFiniteMode,
+    ) -> Result<Self, JsonError> {
+        let values = Parser {
+            bytes: json.as_bytes(),
+            position: 0,
+            mode,
+        }
+        .parse()?;
+        let length = u32::try_from(values.len()).map_err(|_| JsonError::new(json.len()))?;
+        let array = TypedArray::try_new_with_length(kind, length).map_err(|error| JsonError {
+            position: json.len(),
+            allocation_limit_error: Some(error),
+        })?;
+        array.set(&Float64Array::from(&values[..]), 0);
+        Ok(array)
+    }
+}
+
+/// Writes a finite number in the shortest form that reads back as the same
+/// `f64`, or the same `f32` if `single` is set. Like Javascript, very large
+/// and very small numbers use an exponent.
+fn write_number(json: &mut String, x: f64, single: bool) {
+    use core::fmt::Write;
+    let exponential = x != 0.0 && (x.abs() >= 1e21 || x.abs() < 1e-6);
+    let result = match (single, exponential) {
+        (false, false) => write!(json, "{}", x),
+        (false, true) => write!(json, "{:e}", x),
+        (true, false) => write!(json, "{}", x as f32),
+        (true, true) => write!(json, "{:e}", x as f32),
+    };
+    result.expect("writing to a string cannot fail");
+}
+
+/// A parser for a JSON array of numbers.
+struct Parser<'a> {
+    bytes: &'a [u8],
+    position: usize,
+    mode: NonFiniteMode,
+}
+
+impl Parser<'_> {
+    fn parse(mut self) -> Result<Vec<f64>, JsonError> {
+        let mut values = Vec::new();
+        self.skip_whitespace();
+        self.expect(b'[')?;
+        self.skip_whitespace();
+        if self.peek() == Some(b']') {
+            self.position += 1;
+        } else {
+            loop {
+                self.skip_whitespace();
+                values.push(self.value()?);
+                self.skip_whitespace();
+                match self.peek() {
+                    Some(b',') => self.position += 1,
+                    Some(b']') => {
+                        self.position += 1;
+                        break;
+                    }
+                    _ => return Err(self.error()),
+                }
+            }
+        }
+        self.skip_whitespace();
+        if self.position == self.bytes.len() {
+            Ok(values)
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn value(&mut self) -> Result<f64, JsonError> {
+        match (self.peek(), self.mode) {
+            (Some(b'n'), NonFiniteMode::Null) => {
+                self.keyword(b"null")?;
+                Ok(f64::NAN)
+            }
+            (Some(b'"'), NonFiniteMode::String) => {
+                let rest = &self.bytes[self.position..];
+                for &(keyword, value) in &[
+                    (&b"\"NaN\""[..], f64::NAN),
+                    (&b"\"Infinity\""[..], f64::INFINITY),
+                    (&b"\"-Infinity\""[..], f64::NEG_INFINITY),
+                ] {
+                    if rest.starts_with(keyword) {
+                        self.position += keyword.len();
+                        return Ok(value);
+                    }
+                }
+                Err(self.error())
+            }
+            _ => self.number(),
+        }
+    }
+
+    /// Parses a number with the grammar of JSON.
+    fn number(&mut self) -> Result<f64, JsonError> {
+        let start = self.position;
+        self.eat(b'-');
+        if !self.eat(b'0') && self.digits() == 0 {
+            return Err(self.error());
+        }
+        if self.eat(b'.') && self.digits() == 0 {
+            return Err(self.error());
+        }
+        if self.eat(b'e') || self.eat(b'E') {
+            let _ = self.eat(b'+') || self.eat(b'-');
+            if self.digits() == 0 {
+                return Err(self.error());
+            }
+        }
+        core::str::from_utf8(&self.bytes[start..self.position])
+            .ok()
+            .and_then(|number| number.parse().ok())
+            .ok_or(JsonError::new(start))
+    }
+
+    fn digits(&mut self) -> usize {
+        let start = self.position;
+        while matches!(self.peek(), Some(b'0'..=b'9')) {
+            self.position += 1;
+        }
+        self.position - start
+    }
+
+    fn keyword(&mut self, keyword: &[u8]) -> Result<(), JsonError> {
+        if self.bytes[self.position..].starts_with(keyword) {
+            self.position += keyword.len();
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), JsonError> {
+        if self.eat(byte) {
+            Ok(())
+        } else {
+            Err(self.error())
+        }
+    }
+
+    fn eat(&mut self, byte: u8) -> bool {
+        let found = self.peek() == Some(byte);
+        if found {
+            self.position += 1;
+        }
+        found
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.position += 1;
+        }
+    }
+
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.position).copied()
+    }
+
+    fn error(&self) -> JsonError {
+        JsonError::new(self.position)
+    }
+}
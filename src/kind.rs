@@ -0,0 +1,65 @@
+//! The kinds of typed arrays.
+
+/// The kind of a `TypedArray`, which determines its element type. There is a
+/// kind for every `TypedArray` variant.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum TypedArrayKind {
+    Int8Array,
+    Uint8Array,
+    Uint8ClampedArray,
+    Int16Array,
+    Uint16Array,
+    Int32Array,
+    Uint32Array,
+    Float32Array,
+    Float64Array,
+}
+
+impl TypedArrayKind {
+    /// The name of the Javascript constructor of this kind, like
+    /// `"Float32Array"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            TypedArrayKind::Int8Array => "Int8Array",
+            TypedArrayKind::Uint8Array => "Uint8Array",
+            TypedArrayKind::Uint8ClampedArray => "Uint8ClampedArray",
+            TypedArrayKind::Int16Array => "Int16Array",
+            TypedArrayKind::Uint16Array => "Uint16Array",
+            TypedArrayKind::Int32Array => "Int32Array",
+            TypedArrayKind::Uint32Array => "Uint32Array",
+            TypedArrayKind::Float32Array => "Float32Array",
+            TypedArrayKind::Float64Array => "Float64Array",
+        }
+    }
+
+    /// The size in bytes of a single element of this kind, the same as the
+    /// Javascript `BYTES_PER_ELEMENT` property.
+    pub fn bytes_per_element(self) -> u32 {
+        match self {
+            TypedArrayKind::Int8Array
+            | TypedArrayKind::Uint8Array
+            | TypedArrayKind::Uint8ClampedArray => 1,
+            TypedArrayKind::Int16Array | TypedArrayKind::Uint16Array => 2,
+            TypedArrayKind::Int32Array
+            | TypedArrayKind::Uint32Array
+            | TypedArrayKind::Float32Array => 4,
+            TypedArrayKind::Float64Array => 8,
+        }
+    }
+
+    /// The smallest and largest values of this kind if it is an integer kind,
+    /// or `None` for floating point kinds.
+    pub(crate) fn integer_range(self) -> Option<(f64, f64)> {
+        match self {
+            TypedArrayKind::Int8Array => Some((f64::from(i8::MIN), f64::from(i8::MAX))),
+            TypedArrayKind::Uint8Array | TypedArrayKind::Uint8ClampedArray => {
+                Some((0.0, f64::from(u8::MAX)))
+            }
+            TypedArrayKind::Int16Array => Some((f64::from(i16::MIN), f64::from(i16::MAX))),
+            TypedArrayKind::Uint16Array => Some((0.0, f64::from(u16::MAX))),
+            TypedArrayKind::Int32Array => Some((f64::from(i32::MIN), f64::from(i32::MAX))),
+            TypedArrayKind::Uint32Array => Some((0.0, f64::from(u32::MAX))),
+            TypedArrayKind::Float32Array | TypedArrayKind::Float64Array => None,
+        }
+    }
+}
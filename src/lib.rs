@@ -5,6 +5,10 @@
 //! and [`byte_length`] in common. However, they can be tricky to work with if
 //! the variant is not known. This wrapper type makes it easy to use all typed
 //! arrays in the way you'd expect.
+//!
+//! Note that cloning a `TypedArray` does not copy any data: it creates another
+//! handle to the same Javascript object, which shares its memory with the
+//! original. Use [`duplicate`] to copy the elements into a new buffer.
 //!  
 //! # Examples
 //! ```rust
@@ -25,42 +29,489 @@
 //! The following common functionality between typed arrays is implemented:
 //!
 //! - [`buffer`]
+//! - [`backing_buffer`], which may be a `SharedArrayBuffer`, and [`is_shared`]
+//! - [`slice_buffer`]
+//! - [`same_buffer_as`]
+//! - [`content_eq`]
+//! - [`cmp_contents`]
+//! - [`hash_contents`] and [`HashableTypedArray`]
+//! - [`hash_chunks`] and [`digest_streaming`] with bounded memory
+//! - [`diff`] and [`diff_with_ranges`], returning a [`DiffReport`]
+//! - [`xor_with`] and [`apply_delta`]
+//! - [`approx_eq`] and [`approx_eq_relative`]
+//! - [`overlaps`]
 //! - [`subarray`]
 //! - [`slice`]
+//! - [`duplicate`]
+//! - [`is_detached`]
+//! - [`transfer`] and [`transfer_to_fixed_length`]
+//! - [`is_resizable`], [`max_byte_length`] and [`resize_buffer`]
+//! - [`is_growable`] and [`grow_shared`]
+//! - [`subarray_from`]
+//! - [`slice_from`]
+//! - [`split_at`]
+//! - [`split_off`]
+//! - [`chunks`]
+//! - [`windows`]
+//! - [`concat`]
+//! - [`repeat`]
+//! - [`checked_subarray`]
+//! - [`subarray_range`]
+//! - [`slice_range`]
 //! - [`length`]
+//! - [`snapshot_length`]
+//! - [`is_empty`]
+//! - [`first`]
+//! - [`last`]
 //! - [`byte_length`]
 //! - [`byte_offset`]
 //! - [`set`]
+//! - [`get_index`]
+//! - [`fill`]
+//! - [`fill_with`]
+//! - [`copy_within`]
+//! - [`reverse`]
+//! - [`sort`]
+//! - [`sort_by`]
+//! - [`index_of`]
+//! - [`last_index_of`]
+//! - [`includes`]
+//! - [`binary_search`] and [`binary_search_many`], for sorted typed arrays
+//! - [`gather`] and [`scatter`], which read and write elements by index
+//! - [`argmin`], [`argmax`] and [`argsort`], which find the indices of values
+//! - [`cumsum`] and [`prefix_scan`], for cumulative sums and similar operations
+//! - [`rotate_left`] and [`rotate_right`], which rotate the elements in place
+//! - [`contains_nan`], [`contains_infinite`] and [`find_first_non_finite`]
+//! - [`find`]
+//! - [`find_index`]
+//! - [`every`]
+//! - [`some`]
+//! - [`join`], and `Display` like `toString()`
+//! - `Debug` showing the kind, length and elements, and [`debug_preview`]
+//! - `LowerHex` and `UpperHex` for hexadecimal dumps of the elements
+//! - [`to_locale_string`]
+//! - [`for_each`]
+//! - [`map`]
+//! - [`filter`]
+//! - [`reduce`]
+//! - [`reduce_right`]
+//! - [`entries`]
+//! - [`keys`]
+//! - [`values`]
+//! - [`iter`]
+//! - [`iter_chunks`]
 //!
 //! Additionally, conversions are easy:
 //!
 //! - `From<X> for TypedArray`
+//! - [`new_with_length`] and [`kind`], using [`TypedArrayKind`]
+//! - [`try_new_with_length`], which respects the limit set with [`set_allocation_limit`]
+//! - [`new_length_tracking`], a view that follows the length of a resizable buffer
+//! - [`from_shared`], a view of a `SharedArrayBuffer`, and [`from_shared_length_tracking`]
+//! - [`convert_to`], converting the values to another kind using a [`ConversionMode`]
+//! - [`to_json_string`] and [`from_json_str`], for JSON arrays of numbers, using a [`NonFiniteMode`]
 //! - `TryFrom<TypedArray> for X`
 //! - `TryFrom<JsValue> for TypedArray`
 //! - `AsRef<JsValue> for TypedArray`
 //! - `AsRef<Object> for TypedArray`
+//! - `IntoIterator for &TypedArray`, the same as [`iter`]
 //! - [`has_type`], analogous to `JsCast::has_type`
 //! - [`dyn_into`], analogous to `JsCast::dyn_into`
 //!
+//! # Allocation
+//! Typed arrays that are needed only briefly can reuse buffers instead of
+//! allocating new ones:
+//!
+//! - [`TypedArrayPool`], which hands out [`PooledTypedArray`]s and takes their buffers back when they are dropped
+//! - [`ScratchArena`], which hands out views of a single preallocated buffer until it is reset
+//!
+//! # Binary data
+//! The memory viewed by any typed array can be accessed as bytes:
+//!
+//! - [`as_bytes`]
+//! - [`as_bytes_typed_array`]
+//! - [`byte_subarray`]
+//! - [`reinterpret_as`], a view of the same bytes as another kind
+//! - [`bytes_per_element`]
+//! - [`data_view`]
+//! - [`data_view_range`]
+//! - `read_u16_le`, `write_u16_le` and the other endianness-aware scalar reads and writes, such as [`read_u32_be`] and [`write_f64_le`]
+//! - [`swap_bytes`]
+//! - [`read_varint_at`] and [`write_varint_at`], for LEB128 varints, and [`read_zigzag_varint_at`] and [`write_zigzag_varint_at`] for signed ones
+//! - [`TypedArrayReader`], a cursor for reading binary data sequentially
+//! - [`TypedArrayWriter`], a cursor for writing binary data sequentially into an existing array
+//! - [`BitReader`], a cursor for reading bit-packed data in either [`BitOrder`]
+//! - [`encode_binary`] and [`decode_binary`], a simple format with a header describing the elements
+//! - [`to_base64`] and [`from_base64`], using either [`Base64Alphabet`]
+//! - [`to_hex`], [`to_hex_upper`] and [`from_hex`]
+//! - [`to_data_url`] and [`from_data_url`]
+//! - [`decode_utf8`], [`decode_utf8_lossy`] and [`decode_utf8_range`], using a `TextDecoder`
+//! - [`from_str_utf8`] and [`encode_into_existing`], which encode strings as UTF-8
+//! - [`StreamingDecoder`], which decodes UTF-8 that arrives in chunks
+//! - [`decode_utf16`] and [`decode_utf16_lossy`]
+//! - [`decode_latin1`] and [`decode_windows1252`], for legacy single byte encodings
+//! - [`to_wav_bytes`] and [`from_wav_bytes`], to wrap PCM samples in a WAV file and read them back as [`WavAudio`]
+//!
+//! # Numerics
+//! Numerical operations copy the elements into WebAssembly memory in chunks,
+//! instead of reading them from Javascript one at a time:
+//!
+//! - [`min`], [`max`], [`sum`] and [`mean`], which treat `NaN` according to a [`NanMode`]
+//! - [`variance`] and [`std_dev`], and [`sample_variance`] and [`sample_std_dev`] with Bessel's correction
+//! - [`histogram`]
+//! - [`add_assign`], [`sub_assign`] and [`mul_assign`], elementwise between typed arrays of the same kind
+//! - [`dot`]
+//! - [`scale_offset`], which computes `a * x + b` in place
+//! - [`clamp_in_place`]
+//! - [`apply`], which transforms every value with a Rust closure
+//! - [`normalize`] and [`normalize_from`], which rescale the values to a range
+//!
+//! # Atomics
+//! The elements of integer typed arrays can be accessed atomically, which is
+//! mostly useful for typed arrays viewing a `SharedArrayBuffer`:
+//!
+//! - [`atomic_load`] and [`atomic_store`]
+//! - `atomic_add`, `atomic_sub`, `atomic_and`, `atomic_or`, `atomic_xor` and [`atomic_exchange`]
+//! - [`atomic_compare_exchange`]
+//! - [`atomic_wait`], [`atomic_wait_async`] and [`atomic_notify`], which only support an `Int32Array`
+//! - [`SharedTypedArray`], a `Send + Sync` handle to a typed array in the shared memory of a threaded module
+//!
+//! # Optional features
+//! Some functionality has to be enabled with a Cargo feature:
+//!
+//! - `watch`: [`watched`], which reports the writes made by Javascript code through a `Proxy`
+//! - `serde`: `Serialize` and `Deserialize` for `TypedArray`, as its kind and its bytes, and for
+//!   `TypedArrayKind`, as the constructor name
+//! - `serde`: the [`bytes`] module, to serialize byte typed arrays in the same format as `serde_bytes`
+//! - `serde-wasm-bindgen`: the [`preserve`] module, to pass typed array objects through `serde_wasm_bindgen` without copying
+//! - `cbor`: [`to_cbor`] and [`from_cbor`], using the typed array tags of RFC 8746
+//! - `msgpack`: [`to_msgpack`] and [`from_msgpack`], using extension types
+//! - `npy`: [`to_npy_bytes`] and [`from_npy_bytes`], to exchange arrays with NumPy in the `.npy` format
+//!
+//! # Minimum supported Rust version
+//! This crate requires Rust 1.81 or later, the same as `wasm-bindgen`.
+//!
 //! [`buffer`]: enum.TypedArray.html#method.buffer
+//! [`backing_buffer`]: enum.TypedArray.html#method.backing_buffer
+//! [`is_shared`]: enum.TypedArray.html#method.is_shared
+//! [`slice_buffer`]: enum.TypedArray.html#method.slice_buffer
+//! [`same_buffer_as`]: enum.TypedArray.html#method.same_buffer_as
+//! [`content_eq`]: enum.TypedArray.html#method.content_eq
+//! [`cmp_contents`]: enum.TypedArray.html#method.cmp_contents
+//! [`hash_contents`]: enum.TypedArray.html#method.hash_contents
+//! [`HashableTypedArray`]: struct.HashableTypedArray.html
+//! [`hash_chunks`]: enum.TypedArray.html#method.hash_chunks
+//! [`digest_streaming`]: enum.TypedArray.html#method.digest_streaming
+//! [`diff`]: enum.TypedArray.html#method.diff
+//! [`diff_with_ranges`]: enum.TypedArray.html#method.diff_with_ranges
+//! [`DiffReport`]: struct.DiffReport.html
+//! [`xor_with`]: enum.TypedArray.html#method.xor_with
+//! [`apply_delta`]: enum.TypedArray.html#method.apply_delta
+//! [`approx_eq`]: enum.TypedArray.html#method.approx_eq
+//! [`approx_eq_relative`]: enum.TypedArray.html#method.approx_eq_relative
+//! [`overlaps`]: enum.TypedArray.html#method.overlaps
 //! [`subarray`]: enum.TypedArray.html#method.subarray
 //! [`slice`]: enum.TypedArray.html#method.slice
+//! [`duplicate`]: enum.TypedArray.html#method.duplicate
+//! [`is_detached`]: enum.TypedArray.html#method.is_detached
+//! [`transfer`]: enum.TypedArray.html#method.transfer
+//! [`transfer_to_fixed_length`]: enum.TypedArray.html#method.transfer_to_fixed_length
+//! [`is_resizable`]: enum.TypedArray.html#method.is_resizable
+//! [`max_byte_length`]: enum.TypedArray.html#method.max_byte_length
+//! [`resize_buffer`]: enum.TypedArray.html#method.resize_buffer
+//! [`is_growable`]: enum.TypedArray.html#method.is_growable
+//! [`grow_shared`]: enum.TypedArray.html#method.grow_shared
+//! [`subarray_from`]: enum.TypedArray.html#method.subarray_from
+//! [`slice_from`]: enum.TypedArray.html#method.slice_from
+//! [`split_at`]: enum.TypedArray.html#method.split_at
+//! [`split_off`]: enum.TypedArray.html#method.split_off
+//! [`chunks`]: enum.TypedArray.html#method.chunks
+//! [`windows`]: enum.TypedArray.html#method.windows
+//! [`concat`]: enum.TypedArray.html#method.concat
+//! [`repeat`]: enum.TypedArray.html#method.repeat
+//! [`checked_subarray`]: enum.TypedArray.html#method.checked_subarray
+//! [`subarray_range`]: enum.TypedArray.html#method.subarray_range
+//! [`slice_range`]: enum.TypedArray.html#method.slice_range
 //! [`length`]: enum.TypedArray.html#method.length
+//! [`snapshot_length`]: enum.TypedArray.html#method.snapshot_length
+//! [`is_empty`]: enum.TypedArray.html#method.is_empty
+//! [`first`]: enum.TypedArray.html#method.first
+//! [`last`]: enum.TypedArray.html#method.last
 //! [`byte_length`]: enum.TypedArray.html#method.byte_length
 //! [`byte_offset`]: enum.TypedArray.html#method.byte_offset
 //! [`set`]: enum.TypedArray.html#method.set
+//! [`get_index`]: enum.TypedArray.html#method.get_index
+//! [`fill`]: enum.TypedArray.html#method.fill
+//! [`fill_with`]: enum.TypedArray.html#method.fill_with
+//! [`copy_within`]: enum.TypedArray.html#method.copy_within
+//! [`reverse`]: enum.TypedArray.html#method.reverse
+//! [`sort`]: enum.TypedArray.html#method.sort
+//! [`sort_by`]: enum.TypedArray.html#method.sort_by
+//! [`index_of`]: enum.TypedArray.html#method.index_of
+//! [`last_index_of`]: enum.TypedArray.html#method.last_index_of
+//! [`includes`]: enum.TypedArray.html#method.includes
+//! [`binary_search`]: enum.TypedArray.html#method.binary_search
+//! [`binary_search_many`]: enum.TypedArray.html#method.binary_search_many
+//! [`gather`]: enum.TypedArray.html#method.gather
+//! [`scatter`]: enum.TypedArray.html#method.scatter
+//! [`argmin`]: enum.TypedArray.html#method.argmin
+//! [`argmax`]: enum.TypedArray.html#method.argmax
+//! [`argsort`]: enum.TypedArray.html#method.argsort
+//! [`cumsum`]: enum.TypedArray.html#method.cumsum
+//! [`prefix_scan`]: enum.TypedArray.html#method.prefix_scan
+//! [`rotate_left`]: enum.TypedArray.html#method.rotate_left
+//! [`rotate_right`]: enum.TypedArray.html#method.rotate_right
+//! [`contains_nan`]: enum.TypedArray.html#method.contains_nan
+//! [`contains_infinite`]: enum.TypedArray.html#method.contains_infinite
+//! [`find_first_non_finite`]: enum.TypedArray.html#method.find_first_non_finite
+//! [`find`]: enum.TypedArray.html#method.find
+//! [`find_index`]: enum.TypedArray.html#method.find_index
+//! [`every`]: enum.TypedArray.html#method.every
+//! [`some`]: enum.TypedArray.html#method.some
+//! [`join`]: enum.TypedArray.html#method.join
+//! [`debug_preview`]: enum.TypedArray.html#method.debug_preview
+//! [`to_locale_string`]: enum.TypedArray.html#method.to_locale_string
+//! [`for_each`]: enum.TypedArray.html#method.for_each
+//! [`map`]: enum.TypedArray.html#method.map
+//! [`filter`]: enum.TypedArray.html#method.filter
+//! [`reduce`]: enum.TypedArray.html#method.reduce
+//! [`reduce_right`]: enum.TypedArray.html#method.reduce_right
+//! [`entries`]: enum.TypedArray.html#method.entries
+//! [`keys`]: enum.TypedArray.html#method.keys
+//! [`values`]: enum.TypedArray.html#method.values
 //! [`has_type`]: enum.TypedArray.html#method.has_type
 //! [`dyn_into`]: enum.TypedArray.html#method.dyn_into
+//! [`as_bytes`]: enum.TypedArray.html#method.as_bytes
+//! [`as_bytes_typed_array`]: enum.TypedArray.html#method.as_bytes_typed_array
+//! [`byte_subarray`]: enum.TypedArray.html#method.byte_subarray
+//! [`reinterpret_as`]: enum.TypedArray.html#method.reinterpret_as
+//! [`bytes_per_element`]: enum.TypedArray.html#method.bytes_per_element
+//! [`data_view`]: enum.TypedArray.html#method.data_view
+//! [`data_view_range`]: enum.TypedArray.html#method.data_view_range
+//! [`swap_bytes`]: enum.TypedArray.html#method.swap_bytes
+//! [`read_u32_be`]: enum.TypedArray.html#method.read_u32_be
+//! [`read_varint_at`]: enum.TypedArray.html#method.read_varint_at
+//! [`write_varint_at`]: enum.TypedArray.html#method.write_varint_at
+//! [`read_zigzag_varint_at`]: enum.TypedArray.html#method.read_zigzag_varint_at
+//! [`write_zigzag_varint_at`]: enum.TypedArray.html#method.write_zigzag_varint_at
+//! [`write_f64_le`]: enum.TypedArray.html#method.write_f64_le
+//! [`TypedArrayReader`]: struct.TypedArrayReader.html
+//! [`BitReader`]: struct.BitReader.html
+//! [`BitOrder`]: enum.BitOrder.html
+//! [`TypedArrayWriter`]: struct.TypedArrayWriter.html
+//! [`atomic_load`]: enum.TypedArray.html#method.atomic_load
+//! [`atomic_store`]: enum.TypedArray.html#method.atomic_store
+//! [`atomic_exchange`]: enum.TypedArray.html#method.atomic_exchange
+//! [`atomic_compare_exchange`]: enum.TypedArray.html#method.atomic_compare_exchange
+//! [`atomic_wait`]: enum.TypedArray.html#method.atomic_wait
+//! [`atomic_wait_async`]: enum.TypedArray.html#method.atomic_wait_async
+//! [`atomic_notify`]: enum.TypedArray.html#method.atomic_notify
+//! [`watched`]: enum.TypedArray.html#method.watched
+//! [`encode_binary`]: enum.TypedArray.html#method.encode_binary
+//! [`decode_binary`]: enum.TypedArray.html#method.decode_binary
+//! [`to_base64`]: enum.TypedArray.html#method.to_base64
+//! [`from_base64`]: enum.TypedArray.html#method.from_base64
+//! [`Base64Alphabet`]: enum.Base64Alphabet.html
+//! [`to_hex`]: enum.TypedArray.html#method.to_hex
+//! [`to_hex_upper`]: enum.TypedArray.html#method.to_hex_upper
+//! [`from_hex`]: enum.TypedArray.html#method.from_hex
+//! [`to_data_url`]: enum.TypedArray.html#method.to_data_url
+//! [`from_data_url`]: enum.TypedArray.html#method.from_data_url
+//! [`decode_utf8`]: enum.TypedArray.html#method.decode_utf8
+//! [`decode_utf8_lossy`]: enum.TypedArray.html#method.decode_utf8_lossy
+//! [`decode_utf8_range`]: enum.TypedArray.html#method.decode_utf8_range
+//! [`from_str_utf8`]: enum.TypedArray.html#method.from_str_utf8
+//! [`encode_into_existing`]: enum.TypedArray.html#method.encode_into_existing
+//! [`StreamingDecoder`]: struct.StreamingDecoder.html
+//! [`decode_utf16`]: enum.TypedArray.html#method.decode_utf16
+//! [`decode_utf16_lossy`]: enum.TypedArray.html#method.decode_utf16_lossy
+//! [`decode_latin1`]: enum.TypedArray.html#method.decode_latin1
+//! [`decode_windows1252`]: enum.TypedArray.html#method.decode_windows1252
+//! [`to_wav_bytes`]: enum.TypedArray.html#method.to_wav_bytes
+//! [`from_wav_bytes`]: enum.TypedArray.html#method.from_wav_bytes
+//! [`WavAudio`]: struct.WavAudio.html
+//! [`bytes`]: bytes/index.html
+//! [`preserve`]: preserve/index.html
+//! [`to_cbor`]: enum.TypedArray.html#method.to_cbor
+//! [`from_cbor`]: enum.TypedArray.html#method.from_cbor
+//! [`to_msgpack`]: enum.TypedArray.html#method.to_msgpack
+//! [`from_msgpack`]: enum.TypedArray.html#method.from_msgpack
+//! [`to_npy_bytes`]: enum.TypedArray.html#method.to_npy_bytes
+//! [`from_npy_bytes`]: enum.TypedArray.html#method.from_npy_bytes
+//! [`min`]: enum.TypedArray.html#method.min
+//! [`max`]: enum.TypedArray.html#method.max
+//! [`sum`]: enum.TypedArray.html#method.sum
+//! [`mean`]: enum.TypedArray.html#method.mean
+//! [`NanMode`]: enum.NanMode.html
+//! [`variance`]: enum.TypedArray.html#method.variance
+//! [`std_dev`]: enum.TypedArray.html#method.std_dev
+//! [`sample_variance`]: enum.TypedArray.html#method.sample_variance
+//! [`sample_std_dev`]: enum.TypedArray.html#method.sample_std_dev
+//! [`histogram`]: enum.TypedArray.html#method.histogram
+//! [`add_assign`]: enum.TypedArray.html#method.add_assign
+//! [`sub_assign`]: enum.TypedArray.html#method.sub_assign
+//! [`mul_assign`]: enum.TypedArray.html#method.mul_assign
+//! [`dot`]: enum.TypedArray.html#method.dot
+//! [`scale_offset`]: enum.TypedArray.html#method.scale_offset
+//! [`clamp_in_place`]: enum.TypedArray.html#method.clamp_in_place
+//! [`apply`]: enum.TypedArray.html#method.apply
+//! [`normalize`]: enum.TypedArray.html#method.normalize
+//! [`normalize_from`]: enum.TypedArray.html#method.normalize_from
+//! [`SharedTypedArray`]: struct.SharedTypedArray.html
+//! [`TypedArrayPool`]: struct.TypedArrayPool.html
+//! [`PooledTypedArray`]: struct.PooledTypedArray.html
+//! [`ScratchArena`]: struct.ScratchArena.html
+//! [`new_with_length`]: enum.TypedArray.html#method.new_with_length
+//! [`try_new_with_length`]: enum.TypedArray.html#method.try_new_with_length
+//! [`set_allocation_limit`]: fn.set_allocation_limit.html
+//! [`new_length_tracking`]: enum.TypedArray.html#method.new_length_tracking
+//! [`from_shared`]: enum.TypedArray.html#method.from_shared
+//! [`from_shared_length_tracking`]: enum.TypedArray.html#method.from_shared_length_tracking
+//! [`kind`]: enum.TypedArray.html#method.kind
+//! [`TypedArrayKind`]: enum.TypedArrayKind.html
+//! [`convert_to`]: enum.TypedArray.html#method.convert_to
+//! [`ConversionMode`]: enum.ConversionMode.html
+//! [`to_json_string`]: enum.TypedArray.html#method.to_json_string
+//! [`from_json_str`]: enum.TypedArray.html#method.from_json_str
+//! [`NonFiniteMode`]: enum.NonFiniteMode.html
+//! [`iter`]: enum.TypedArray.html#method.iter
+//! [`iter_chunks`]: enum.TypedArray.html#method.iter_chunks
 
 #![doc(html_root_url = "https://docs.rs/typed-array/0.2.0")]
 
+// Defined before the modules, so that they can use it as well.
+macro_rules! match_every {
+    ($to_match:expr, $name:ident, $x:expr) => {
+        match $to_match {
+            TypedArray::Int8Array($name) => $x,
+            TypedArray::Uint8Array($name) => $x,
+            TypedArray::Uint8ClampedArray($name) => $x,
+            TypedArray::Int16Array($name) => $x,
+            TypedArray::Uint16Array($name) => $x,
+            TypedArray::Int32Array($name) => $x,
+            TypedArray::Uint32Array($name) => $x,
+            TypedArray::Float32Array($name) => $x,
+            TypedArray::Float64Array($name) => $x,
+        }
+    };
+    ($to_match:expr, $name:ident: $array:ident, $x:expr) => {
+        match $to_match {
+            TypedArray::Int8Array($name) => {
+                type $array = Int8Array;
+                $x
+            }
+            TypedArray::Uint8Array($name) => {
+                type $array = Uint8Array;
+                $x
+            }
+            TypedArray::Uint8ClampedArray($name) => {
+                type $array = Uint8ClampedArray;
+                $x
+            }
+            TypedArray::Int16Array($name) => {
+                type $array = Int16Array;
+                $x
+            }
+            TypedArray::Uint16Array($name) => {
+                type $array = Uint16Array;
+                $x
+            }
+            TypedArray::Int32Array($name) => {
+                type $array = Int32Array;
+                $x
+            }
+            TypedArray::Uint32Array($name) => {
+                type $array = Uint32Array;
+                $x
+            }
+            TypedArray::Float32Array($name) => {
+                type $array = Float32Array;
+                $x
+            }
+            TypedArray::Float64Array($name) => {
+                type $array = Float64Array;
+                $x
+            }
+        }
+    };
+}
+
+mod arena;
+mod arith;
+mod atomics;
+mod bits;
+mod buffer;
+#[cfg(feature = "serde")]
+pub mod bytes;
+#[cfg(feature = "cbor")]
+mod cbor;
+mod compare;
+mod convert;
+mod cursor;
+mod delta;
+mod encoding;
+mod endian;
+mod fmt;
+mod gather;
+mod iter;
+mod json;
+mod kind;
+mod limit;
+#[cfg(feature = "msgpack")]
+mod msgpack;
+#[cfg(feature = "npy")]
+mod npy;
+mod order;
+mod pool;
+mod prefix;
+#[cfg(feature = "serde-wasm-bindgen")]
+pub mod preserve;
+mod scan;
+mod search;
+#[cfg(feature = "serde")]
+mod serialize;
+mod shared;
+mod stats;
+mod sys;
+mod text;
+mod transform;
+mod varint;
+#[cfg(feature = "watch")]
+mod watch;
+mod wav;
+mod wire;
+
+pub use arena::{ArenaFullError, ScratchArena};
+pub use atomics::{AtomicKindError, AtomicWaitResult};
+pub use bits::{BitOrder, BitReader, EndOfBitsError};
+pub use buffer::TypedArrayBuffer;
+pub use compare::{DiffReport, HashableTypedArray};
+pub use convert::ConversionMode;
+use core::cmp::Ordering;
 use core::convert::TryFrom;
+use core::ops::{Bound, RangeBounds};
+pub use cursor::{EndOfBufferError, TypedArrayReader, TypedArrayWriter};
+pub use encoding::{StreamingDecoder, Utf16Error};
 use err_derive::*;
+pub use fmt::DebugPreview;
+pub use gather::{GatherError, IndexError, ScatterError};
+pub use iter::{Chunks, Iter, IterChunks, Windows};
 use js_sys::{
-    ArrayBuffer, Float32Array, Float64Array, Int16Array, Int32Array, Int8Array, Uint16Array,
-    Uint32Array, Uint8Array, Uint8ClampedArray,
+    ArrayBuffer, DataView, Float32Array, Float64Array, Int16Array, Int32Array, Int8Array, JsString,
+    SharedArrayBuffer, Uint16Array, Uint32Array, Uint8Array, Uint8ClampedArray,
 };
+pub use json::{JsonError, NonFiniteError, NonFiniteMode};
+pub use kind::TypedArrayKind;
+pub use limit::{allocation_limit, set_allocation_limit, AllocationLimitError};
+pub use pool::{PooledTypedArray, TypedArrayPool};
+pub use shared::{NotSharedMemoryError, SharedTypedArray};
+pub use stats::NanMode;
+use sys::{AnyArrayBuffer, AnyTypedArray};
+pub use text::Base64Alphabet;
+pub use varint::VarintError;
 use wasm_bindgen::{JsCast, JsValue};
+pub use wav::{WavAudio, WavKindError};
+pub use wire::DecodeError;
 
 /// Returned when attempting to convert a `TypedArray` to a specific typed
 /// array instance.
@@ -70,6 +521,151 @@ pub struct TryFromTypedArrayError {
     _priv: (),
 }
 
+/// Returned when a range of elements is inverted or out of bounds for a typed
+/// array.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error(
+    display = "range {}..{} is invalid for a typed array of length {}",
+    begin,
+    end,
+    length
+)]
+pub struct RangeError {
+    begin: u32,
+    end: u32,
+    length: u32,
+}
+
+impl RangeError {
+    /// The start of the requested range.
+    pub fn begin(&self) -> u32 {
+        self.begin
+    }
+
+    /// The end of the requested range.
+    pub fn end(&self) -> u32 {
+        self.end
+    }
+
+    /// The length of the typed array at the time of the request.
+    pub fn length(&self) -> u32 {
+        self.length
+    }
+}
+
+/// Returned when a range of bytes is inverted, out of bounds, or not aligned
+/// to the elements of a typed array.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error(
+    display = "byte range {}..{} is invalid for a typed array of {} bytes with {} bytes per element",
+    begin,
+    end,
+    byte_length,
+    bytes_per_element
+)]
+pub struct ByteRangeError {
+    begin: u32,
+    end: u32,
+    byte_length: u32,
+    bytes_per_element: u32,
+}
+
+impl ByteRangeError {
+    /// The start of the requested range, in bytes.
+    pub fn begin(&self) -> u32 {
+        self.begin
+    }
+
+    /// The end of the requested range, in bytes.
+    pub fn end(&self) -> u32 {
+        self.end
+    }
+
+    /// The length of the typed array in bytes at the time of the request.
+    pub fn byte_length(&self) -> u32 {
+        self.byte_length
+    }
+
+    /// The size of an element of the typed array, to which both ends of the
+    /// range must be aligned.
+    pub fn bytes_per_element(&self) -> u32 {
+        self.bytes_per_element
+    }
+}
+
+/// Returned when typed arrays of different kinds are combined.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error(
+    display = "expected a typed array of kind {:?}, found {:?}",
+    expected,
+    found
+)]
+pub struct KindMismatchError {
+    expected: TypedArrayKind,
+    found: TypedArrayKind,
+}
+
+impl KindMismatchError {
+    /// The kind that was required.
+    pub fn expected(&self) -> TypedArrayKind {
+        self.expected
+    }
+
+    /// The kind of the typed array that was provided instead.
+    pub fn found(&self) -> TypedArrayKind {
+        self.found
+    }
+}
+
+/// Returned by [`concat`] when the typed arrays cannot be concatenated.
+///
+/// [`concat`]: enum.TypedArray.html#method.concat
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+pub enum ConcatError {
+    /// A part is of a different kind than the first part.
+    #[error(display = "{}", _0)]
+    KindMismatch(#[error(cause)] KindMismatchError),
+    /// The new typed array would exceed the limit set with
+    /// [`set_allocation_limit`].
+    ///
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    #[error(display = "{}", _0)]
+    AllocationLimit(#[error(cause)] AllocationLimitError),
+}
+
+/// Returned when the bytes of a typed array cannot be viewed as elements of
+/// another kind, because the start is not aligned to the new element size or
+/// the length is not a multiple of it.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error(
+    display = "cannot view {} bytes at byte offset {} as a typed array of kind {:?}",
+    byte_length,
+    byte_offset,
+    kind
+)]
+pub struct AlignmentError {
+    kind: TypedArrayKind,
+    byte_offset: u32,
+    byte_length: u32,
+}
+
+impl AlignmentError {
+    /// The kind that was requested.
+    pub fn kind(&self) -> TypedArrayKind {
+        self.kind
+    }
+
+    /// The offset of the bytes into their buffer.
+    pub fn byte_offset(&self) -> u32 {
+        self.byte_offset
+    }
+
+    /// The number of bytes that were to be viewed.
+    pub fn byte_length(&self) -> u32 {
+        self.byte_length
+    }
+}
+
 macro_rules! impl_from {
     ($arr:ident) => {
         impl From<$arr> for TypedArray {
@@ -95,7 +691,13 @@ macro_rules! impl_from {
 /// An enum of all of the typed arrays that is available in Javascipt. Common
 /// functionality is implemented on this type to make handling all variants
 /// easier.
-#[derive(Clone, Debug)]
+///
+/// Cloning a `TypedArray` only clones the handle to the Javascript object, so
+/// the clone views the same memory and writes through either are visible in
+/// both. Use [`duplicate`] to copy the elements into a new buffer instead.
+///
+/// [`duplicate`]: #method.duplicate
+#[derive(Clone)]
 pub enum TypedArray {
     Int8Array(Int8Array),
     Uint8Array(Uint8Array),
@@ -118,29 +720,604 @@ impl_from!(Uint32Array);
 impl_from!(Float32Array);
 impl_from!(Float64Array);
 
-macro_rules! match_every {
-    ($to_match:expr, $name:ident, $x:expr) => {
-        match $to_match {
-            TypedArray::Int8Array($name) => $x,
-            TypedArray::Uint8Array($name) => $x,
-            TypedArray::Uint8ClampedArray($name) => $x,
-            TypedArray::Int16Array($name) => $x,
-            TypedArray::Uint16Array($name) => $x,
-            TypedArray::Int32Array($name) => $x,
-            TypedArray::Uint32Array($name) => $x,
-            TypedArray::Float32Array($name) => $x,
-            TypedArray::Float64Array($name) => $x,
-        }
+/// The element types of the typed arrays. Every element type can be converted
+/// to `f64` without loss, and from `f64` with an `as` cast.
+trait Element: Copy {
+    fn to_f64(self) -> f64;
+    fn from_f64(value: f64) -> Self;
+}
+
+macro_rules! impl_element {
+    ($($ty:ident),*) => {
+        $(
+            impl Element for $ty {
+                fn to_f64(self) -> f64 {
+                    self as f64
+                }
+
+                fn from_f64(value: f64) -> Self {
+                    value as $ty
+                }
+            }
+        )*
     };
 }
 
+impl_element!(i8, u8, i16, u16, i32, u32, f32, f64);
+
+impl TypedArray {
+    /// Rounds a value the way Javascript does when storing it in this typed
+    /// array, as far as that differs from `Element::from_f64`. Only a
+    /// `Uint8ClampedArray` rounds, to the nearest integer with ties to even;
+    /// the `as` cast then clamps the result and turns `NaN` into zero.
+    fn round_for_storage(&self, value: f64) -> f64 {
+        if self.kind() != TypedArrayKind::Uint8ClampedArray {
+            return value;
+        }
+        let floor = value.floor();
+        let fraction = value - floor;
+        if fraction > 0.5 || (fraction == 0.5 && floor % 2.0 != 0.0) {
+            floor + 1.0
+        } else {
+            floor
+        }
+    }
+}
+
+/// The number of elements that methods processing a whole typed array copy into
+/// Rust at once. This bounds the memory they use for large typed arrays.
+const CHUNK_LEN: u32 = 16 * 1024;
+
+/// The ranges of at most `chunk_len` elements that cover the first `length`
+/// elements, as pairs of a begin and an end index.
+fn chunk_ranges(length: u32, chunk_len: u32) -> impl DoubleEndedIterator<Item = (u32, u32)> {
+    (0..length.div_ceil(chunk_len)).map(move |chunk| {
+        let begin = chunk * chunk_len;
+        (begin, begin.saturating_add(chunk_len).min(length))
+    })
+}
+
+/// Converts an index returned by a Javascript search method, which is `-1` if
+/// nothing was found, to an `Option`.
+fn found_index(index: i32) -> Option<u32> {
+    if index < 0 {
+        None
+    } else {
+        Some(index as u32)
+    }
+}
+
 impl TypedArray {
+    /// Creates a new typed array of the given kind, with an internal buffer
+    /// large enough for `length` elements. All elements are zero.
+    ///
+    /// # Panics
+    /// Panics if the buffer would exceed the limit set with
+    /// [`set_allocation_limit`]. Use [`try_new_with_length`] to handle this.
+    ///
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    /// [`try_new_with_length`]: #method.try_new_with_length
+    pub fn new_with_length(kind: TypedArrayKind, length: u32) -> Self {
+        TypedArray::try_new_with_length(kind, length).unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// The same as [`new_with_length`], but returns an error if the buffer
+    /// would exceed the limit set with [`set_allocation_limit`].
+    ///
+    /// [`new_with_length`]: #method.new_with_length
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    pub fn try_new_with_length(
+        kind: TypedArrayKind,
+        length: u32,
+    ) -> Result<Self, AllocationLimitError> {
+        limit::check_allocation(u64::from(length) * u64::from(kind.bytes_per_element()))?;
+        Ok(match kind {
+            TypedArrayKind::Int8Array => Int8Array::new_with_length(length).into(),
+            TypedArrayKind::Uint8Array => Uint8Array::new_with_length(length).into(),
+            TypedArrayKind::Uint8ClampedArray => Uint8ClampedArray::new_with_length(length).into(),
+            TypedArrayKind::Int16Array => Int16Array::new_with_length(length).into(),
+            TypedArrayKind::Uint16Array => Uint16Array::new_with_length(length).into(),
+            TypedArrayKind::Int32Array => Int32Array::new_with_length(length).into(),
+            TypedArrayKind::Uint32Array => Uint32Array::new_with_length(length).into(),
+            TypedArrayKind::Float32Array => Float32Array::new_with_length(length).into(),
+            TypedArrayKind::Float64Array => Float64Array::new_with_length(length).into(),
+        })
+    }
+
+    /// Creates a typed array of the given kind viewing `buffer` from
+    /// `byte_offset` to its end, without an explicit length. If `buffer` is
+    /// resizable, the typed array tracks its length: elements are added and
+    /// removed as the buffer is resized, so [`length`] may change between
+    /// calls.
+    ///
+    /// # Panics
+    /// Panics if `byte_offset` is not a multiple of the element size, or is
+    /// greater than the byte length of the buffer.
+    ///
+    /// [`length`]: #method.length
+    pub fn new_length_tracking(
+        kind: TypedArrayKind,
+        buffer: &ArrayBuffer,
+        byte_offset: u32,
+    ) -> Self {
+        TypedArray::new_with_byte_offset(kind, buffer, byte_offset)
+    }
+
+    /// The same as [`new_length_tracking`], but for a `SharedArrayBuffer`. If
+    /// the buffer is growable, the typed array grows along with it.
+    ///
+    /// # Panics
+    /// Panics if `byte_offset` is not a multiple of the element size, or is
+    /// greater than the byte length of the buffer.
+    ///
+    /// [`new_length_tracking`]: #method.new_length_tracking
+    pub fn from_shared_length_tracking(
+        kind: TypedArrayKind,
+        buffer: &SharedArrayBuffer,
+        byte_offset: u32,
+    ) -> Self {
+        TypedArray::new_with_byte_offset(kind, buffer, byte_offset)
+    }
+
+    /// Creates a typed array of the given kind viewing `buffer`, which is an
+    /// `ArrayBuffer` or a `SharedArrayBuffer`, from `byte_offset` to its end.
+    fn new_with_byte_offset(kind: TypedArrayKind, buffer: &JsValue, byte_offset: u32) -> Self {
+        match kind {
+            TypedArrayKind::Int8Array => {
+                Int8Array::new_with_byte_offset(buffer, byte_offset).into()
+            }
+            TypedArrayKind::Uint8Array => {
+                Uint8Array::new_with_byte_offset(buffer, byte_offset).into()
+            }
+            TypedArrayKind::Uint8ClampedArray => {
+                Uint8ClampedArray::new_with_byte_offset(buffer, byte_offset).into()
+            }
+            TypedArrayKind::Int16Array => {
+                Int16Array::new_with_byte_offset(buffer, byte_offset).into()
+            }
+            TypedArrayKind::Uint16Array => {
+                Uint16Array::new_with_byte_offset(buffer, byte_offset).into()
+            }
+            TypedArrayKind::Int32Array => {
+                Int32Array::new_with_byte_offset(buffer, byte_offset).into()
+            }
+            TypedArrayKind::Uint32Array => {
+                Uint32Array::new_with_byte_offset(buffer, byte_offset).into()
+            }
+            TypedArrayKind::Float32Array => {
+                Float32Array::new_with_byte_offset(buffer, byte_offset).into()
+            }
+            TypedArrayKind::Float64Array => {
+                Float64Array::new_with_byte_offset(buffer, byte_offset).into()
+            }
+        }
+    }
+
+    /// Creates a typed array of the given kind viewing `length` elements of
+    /// a `SharedArrayBuffer`, starting at `byte_offset`. Writes through the
+    /// typed array are visible to every thread that shares the buffer.
+    ///
+    /// # Panics
+    /// Panics if `byte_offset` is not a multiple of the element size, or if
+    /// the elements are not within the buffer.
+    pub fn from_shared(
+        kind: TypedArrayKind,
+        buffer: &SharedArrayBuffer,
+        byte_offset: u32,
+        length: u32,
+    ) -> Self {
+        TypedArray::new_with_buffer(kind, buffer, byte_offset, length)
+    }
+
+    /// Creates a typed array of the given kind viewing `length` elements of
+    /// `buffer`, which is an `ArrayBuffer` or a `SharedArrayBuffer`, starting
+    /// at `byte_offset`.
+    fn new_with_buffer(
+        kind: TypedArrayKind,
+        buffer: &JsValue,
+        byte_offset: u32,
+        length: u32,
+    ) -> Self {
+        match kind {
+            TypedArrayKind::Int8Array => {
+                Int8Array::new_with_byte_offset_and_length(buffer, byte_offset, length).into()
+            }
+            TypedArrayKind::Uint8Array => {
+                Uint8Array::new_with_byte_offset_and_length(buffer, byte_offset, length).into()
+            }
+            TypedArrayKind::Uint8ClampedArray => {
+                Uint8ClampedArray::new_with_byte_offset_and_length(buffer, byte_offset, length)
+                    .into()
+            }
+            TypedArrayKind::Int16Array => {
+                Int16Array::new_with_byte_offset_and_length(buffer, byte_offset, length).into()
+            }
+            TypedArrayKind::Uint16Array => {
+                Uint16Array::new_with_byte_offset_and_length(buffer, byte_offset, length).into()
+            }
+            TypedArrayKind::Int32Array => {
+                Int32Array::new_with_byte_offset_and_length(buffer, byte_offset, length).into()
+            }
+            TypedArrayKind::Uint32Array => {
+                Uint32Array::new_with_byte_offset_and_length(buffer, byte_offset, length).into()
+            }
+            TypedArrayKind::Float32Array => {
+                Float32Array::new_with_byte_offset_and_length(buffer, byte_offset, length).into()
+            }
+            TypedArrayKind::Float64Array => {
+                Float64Array::new_with_byte_offset_and_length(buffer, byte_offset, length).into()
+            }
+        }
+    }
+
+    /// Creates a new typed array containing the elements of all `parts` in
+    /// order. The buffer is allocated once, after which every part is copied
+    /// to its offset with [`set`]. All parts must be of the same kind.
+    ///
+    /// Returns an error for the first part of another kind, or if the typed
+    /// array would exceed the limit set with [`set_allocation_limit`].
+    ///
+    /// # Panics
+    /// Panics if `parts` is empty, since the kind of the result would be
+    /// unknown, or if the total length does not fit in a `u32`.
+    ///
+    /// [`set`]: #method.set
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    pub fn concat(parts: &[TypedArray]) -> Result<Self, ConcatError> {
+        let expected = parts
+            .first()
+            .expect("cannot concatenate zero typed arrays")
+            .kind();
+        let mut length: u32 = 0;
+        for part in parts {
+            let found = part.kind();
+            if found != expected {
+                return Err(ConcatError::KindMismatch(KindMismatchError {
+                    expected,
+                    found,
+                }));
+            }
+            length = length
+                .checked_add(part.length())
+                .expect("concatenated length overflows");
+        }
+        let result = TypedArray::try_new_with_length(expected, length)
+            .map_err(ConcatError::AllocationLimit)?;
+        let mut offset = 0;
+        for part in parts {
+            result.set(part.as_ref(), offset);
+            offset += part.length();
+        }
+        Ok(result)
+    }
+
+    /// Creates a new typed array of the same kind, containing the elements of
+    /// this typed array repeated `n` times.
+    ///
+    /// # Panics
+    /// Panics if the resulting length does not fit in a `u32`, or if the typed
+    /// array would exceed the limit set with [`set_allocation_limit`]. Use
+    /// [`try_repeat`] to handle the latter.
+    ///
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    /// [`try_repeat`]: #method.try_repeat
+    pub fn repeat(&self, n: u32) -> Self {
+        self.try_repeat(n)
+            .unwrap_or_else(|error| panic!("{}", error))
+    }
+
+    /// The same as [`repeat`], but returns an error if the typed array would
+    /// exceed the limit set with [`set_allocation_limit`].
+    ///
+    /// # Panics
+    /// Panics if the resulting length does not fit in a `u32`.
+    ///
+    /// [`repeat`]: #method.repeat
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    pub fn try_repeat(&self, n: u32) -> Result<Self, AllocationLimitError> {
+        let length = self.length();
+        let result = TypedArray::try_new_with_length(
+            self.kind(),
+            length.checked_mul(n).expect("repeated length overflows"),
+        )?;
+        for i in 0..n {
+            result.set(self.as_ref(), i * length);
+        }
+        Ok(result)
+    }
+
+    /// Returns a `Uint8Array` view of the bytes of this typed array, from
+    /// [`byte_offset`] up to `byte_offset + byte_length`. The view shares the
+    /// buffer of this typed array, so no data is copied.
+    ///
+    /// [`byte_offset`]: #method.byte_offset
+    pub fn as_bytes(&self) -> Uint8Array {
+        Uint8Array::new_with_byte_offset_and_length(
+            &self.buffer(),
+            self.byte_offset(),
+            self.byte_length(),
+        )
+    }
+
+    /// The same as [`as_bytes`], but wrapped in a `TypedArray`.
+    ///
+    /// [`as_bytes`]: #method.as_bytes
+    pub fn as_bytes_typed_array(&self) -> Self {
+        self.as_bytes().into()
+    }
+
+    /// Creates a view of this typed array addressed by byte offsets relative
+    /// to its start, instead of element indices. Both offsets must be aligned
+    /// to the element size, and the range must be within the typed array.
+    pub fn byte_subarray(&self, byte_begin: u32, byte_end: u32) -> Result<Self, ByteRangeError> {
+        let bytes_per_element = self.bytes_per_element();
+        let byte_length = self.byte_length();
+        if byte_begin <= byte_end
+            && byte_end <= byte_length
+            && byte_begin % bytes_per_element == 0
+            && byte_end % bytes_per_element == 0
+        {
+            Ok(self.subarray(byte_begin / bytes_per_element, byte_end / bytes_per_element))
+        } else {
+            Err(ByteRangeError {
+                begin: byte_begin,
+                end: byte_end,
+                byte_length,
+                bytes_per_element,
+            })
+        }
+    }
+
+    /// Creates a view of the same bytes as this typed array, interpreted as
+    /// elements of `kind`. No data is copied. The byte offset of this typed
+    /// array must be aligned to the element size of `kind`, and its byte
+    /// length must be a multiple of it.
+    pub fn reinterpret_as(&self, kind: TypedArrayKind) -> Result<Self, AlignmentError> {
+        let bytes_per_element = kind.bytes_per_element();
+        let byte_offset = self.byte_offset();
+        let byte_length = self.byte_length();
+        if byte_offset % bytes_per_element == 0 && byte_length % bytes_per_element == 0 {
+            Ok(TypedArray::new_with_buffer(
+                kind,
+                &self.buffer(),
+                byte_offset,
+                byte_length / bytes_per_element,
+            ))
+        } else {
+            Err(AlignmentError {
+                kind,
+                byte_offset,
+                byte_length,
+            })
+        }
+    }
+
+    /// Creates a `DataView` of the bytes of this typed array, which shares its
+    /// buffer and covers exactly the same memory.
+    pub fn data_view(&self) -> DataView {
+        DataView::new(
+            &self.buffer(),
+            self.byte_offset() as usize,
+            self.byte_length() as usize,
+        )
+    }
+
+    /// Creates a `DataView` of `byte_length` bytes of this typed array,
+    /// starting at `byte_begin` bytes from its start.
+    ///
+    /// # Panics
+    /// Panics if the range is not within the bytes of this typed array.
+    pub fn data_view_range(&self, byte_begin: u32, byte_length: u32) -> DataView {
+        let in_bounds = byte_begin
+            .checked_add(byte_length)
+            .is_some_and(|end| end <= self.byte_length());
+        assert!(in_bounds, "byte range out of bounds");
+        DataView::new(
+            &self.buffer(),
+            (self.byte_offset() + byte_begin) as usize,
+            byte_length as usize,
+        )
+    }
+
+    /// The size in bytes of a single element, the same as the Javascript
+    /// `BYTES_PER_ELEMENT` property.
+    pub fn bytes_per_element(&self) -> u32 {
+        self.kind().bytes_per_element()
+    }
+
+    /// Returns the kind of this typed array.
+    pub fn kind(&self) -> TypedArrayKind {
+        match self {
+            TypedArray::Int8Array(_) => TypedArrayKind::Int8Array,
+            TypedArray::Uint8Array(_) => TypedArrayKind::Uint8Array,
+            TypedArray::Uint8ClampedArray(_) => TypedArrayKind::Uint8ClampedArray,
+            TypedArray::Int16Array(_) => TypedArrayKind::Int16Array,
+            TypedArray::Uint16Array(_) => TypedArrayKind::Uint16Array,
+            TypedArray::Int32Array(_) => TypedArrayKind::Int32Array,
+            TypedArray::Uint32Array(_) => TypedArrayKind::Uint32Array,
+            TypedArray::Float32Array(_) => TypedArrayKind::Float32Array,
+            TypedArray::Float64Array(_) => TypedArrayKind::Float64Array,
+        }
+    }
+
     /// The `buffer` accessor property represents the `ArrayBuffer` referenced
     /// by a `TypedArray` at construction time.
+    ///
+    /// If the typed array views a `SharedArrayBuffer`, the result is that
+    /// buffer, typed as an `ArrayBuffer`. Use [`backing_buffer`] to tell the
+    /// two apart.
+    ///
+    /// [`backing_buffer`]: #method.backing_buffer
     pub fn buffer(&self) -> ArrayBuffer {
         match_every!(self, i, i.buffer())
     }
 
+    /// Returns the buffer of this typed array, which is either an
+    /// `ArrayBuffer` or a `SharedArrayBuffer`.
+    pub fn backing_buffer(&self) -> TypedArrayBuffer {
+        let buffer = self.buffer();
+        if buffer.has_type::<SharedArrayBuffer>() {
+            TypedArrayBuffer::SharedArrayBuffer(buffer.unchecked_into())
+        } else {
+            TypedArrayBuffer::ArrayBuffer(buffer)
+        }
+    }
+
+    /// Returns `true` if this typed array views a `SharedArrayBuffer`, such as
+    /// the memory of a threaded WebAssembly module.
+    pub fn is_shared(&self) -> bool {
+        self.buffer().has_type::<SharedArrayBuffer>()
+    }
+
+    /// Returns `true` if the buffer of this typed array has been detached, for
+    /// example because it was transferred to a worker. A detached typed array
+    /// is empty, and most operations on it throw.
+    ///
+    /// This uses `ArrayBuffer.prototype.detached` where it is available.
+    /// Otherwise, an empty buffer is detached if no view can be created on it.
+    pub fn is_detached(&self) -> bool {
+        let buffer = self.buffer();
+        let byte_length = buffer.byte_length();
+        let buffer: AnyArrayBuffer = buffer.unchecked_into();
+        buffer
+            .detached()
+            .unwrap_or_else(|| byte_length == 0 && AnyTypedArray::try_view(&buffer).is_err())
+    }
+
+    /// Moves the buffer of this typed array into a new `ArrayBuffer` with
+    /// `ArrayBuffer.prototype.transfer()`, returning a view of the same kind
+    /// and range over it. The old buffer is detached, including for any other
+    /// views of it. The contents are usually not copied, and a resizable
+    /// buffer stays resizable.
+    ///
+    /// Returns the Javascript exception if the engine does not support
+    /// transferring, or if the buffer cannot be transferred because it is
+    /// shared or already detached.
+    pub fn transfer(self) -> Result<Self, JsValue> {
+        let (kind, byte_offset, length) = (self.kind(), self.byte_offset(), self.length());
+        let buffer = self.buffer().transfer()?;
+        Ok(TypedArray::new_with_buffer(
+            kind,
+            &buffer,
+            byte_offset,
+            length,
+        ))
+    }
+
+    /// The same as [`transfer`], but the new buffer is never resizable.
+    ///
+    /// [`transfer`]: #method.transfer
+    pub fn transfer_to_fixed_length(self) -> Result<Self, JsValue> {
+        let (kind, byte_offset, length) = (self.kind(), self.byte_offset(), self.length());
+        let buffer = self.buffer().transfer_to_fixed_length()?;
+        Ok(TypedArray::new_with_buffer(
+            kind,
+            &buffer,
+            byte_offset,
+            length,
+        ))
+    }
+
+    /// Returns `true` if the buffer of this typed array is a resizable
+    /// `ArrayBuffer`, which can be resized with [`resize_buffer`]. This is
+    /// always `false` in engines that do not support resizable buffers.
+    ///
+    /// [`resize_buffer`]: #method.resize_buffer
+    pub fn is_resizable(&self) -> bool {
+        let buffer: AnyArrayBuffer = self.buffer().unchecked_into();
+        buffer.resizable().unwrap_or(false)
+    }
+
+    /// Returns `true` if the buffer of this typed array is a growable
+    /// `SharedArrayBuffer`, which can be grown with [`grow_shared`].
+    ///
+    /// [`grow_shared`]: #method.grow_shared
+    pub fn is_growable(&self) -> bool {
+        let buffer: AnyArrayBuffer = self.buffer().unchecked_into();
+        buffer.growable().unwrap_or(false)
+    }
+
+    /// The largest length in bytes that the buffer of this typed array can be
+    /// resized or grown to. This is the byte length of a buffer that is
+    /// neither resizable nor growable.
+    pub fn max_byte_length(&self) -> u32 {
+        let buffer = self.buffer();
+        let byte_length = buffer.byte_length();
+        let buffer: AnyArrayBuffer = buffer.unchecked_into();
+        buffer.max_byte_length().unwrap_or(byte_length)
+    }
+
+    /// Resizes the buffer of this typed array in place to `new_byte_len`
+    /// bytes with `ArrayBuffer.prototype.resize()`. The contents are kept up
+    /// to the new length, and added bytes are zero. Every view of the buffer
+    /// sees the change, but a typed array created with an explicit length
+    /// keeps it, and becomes empty while the buffer is too short to hold it.
+    ///
+    /// Returns the Javascript exception if the buffer is not resizable, or if
+    /// `new_byte_len` is greater than [`max_byte_length`].
+    ///
+    /// [`max_byte_length`]: #method.max_byte_length
+    pub fn resize_buffer(&self, new_byte_len: u32) -> Result<(), JsValue> {
+        self.buffer().resize(new_byte_len as usize)
+    }
+
+    /// Grows the `SharedArrayBuffer` of this typed array in place to
+    /// `new_byte_len` bytes with `SharedArrayBuffer.prototype.grow()`. Added
+    /// bytes are zero. Like [`resize_buffer`], a typed array created with an
+    /// explicit length keeps it, while a length-tracking typed array grows.
+    /// Other threads see the new length the next time they read it.
+    ///
+    /// Returns the Javascript exception if the buffer is not a growable
+    /// `SharedArrayBuffer`, or if `new_byte_len` is smaller than its current
+    /// byte length or greater than [`max_byte_length`].
+    ///
+    /// [`resize_buffer`]: #method.resize_buffer
+    /// [`max_byte_length`]: #method.max_byte_length
+    pub fn grow_shared(&self, new_byte_len: u32) -> Result<(), JsValue> {
+        self.buffer()
+            .unchecked_into::<SharedArrayBuffer>()
+            .grow(new_byte_len as usize)
+    }
+
+    /// Copies exactly the bytes viewed by this typed array into a new
+    /// `ArrayBuffer`. Unlike [`buffer`], which may contain unrelated data
+    /// outside of this view, the result can safely be transferred or stored.
+    ///
+    /// [`buffer`]: #method.buffer
+    pub fn slice_buffer(&self) -> ArrayBuffer {
+        let begin = self.byte_offset();
+        self.buffer()
+            .slice_with_end(begin, begin + self.byte_length())
+    }
+
+    /// Returns `true` if this typed array and `other` view the same
+    /// `ArrayBuffer`, in which case writing to one may change the other. See
+    /// [`overlaps`] to check whether the viewed bytes actually intersect.
+    ///
+    /// [`overlaps`]: #method.overlaps
+    pub fn same_buffer_as(&self, other: &TypedArray) -> bool {
+        js_sys::Object::is(self.buffer().as_ref(), other.buffer().as_ref())
+    }
+
+    /// Returns `true` if this typed array and `other` view the same
+    /// `ArrayBuffer` and share at least one byte, so empty typed arrays never
+    /// overlap. Copying between overlapping views with [`set`] still works,
+    /// but the source may be partially overwritten before it is read when
+    /// copying elements one by one.
+    ///
+    /// [`set`]: #method.set
+    pub fn overlaps(&self, other: &TypedArray) -> bool {
+        let begin = self.byte_offset();
+        let end = begin + self.byte_length();
+        let other_begin = other.byte_offset();
+        let other_end = other_begin + other.byte_length();
+        begin < end
+            && other_begin < other_end
+            && begin < other_end
+            && other_begin < end
+            && self.same_buffer_as(other)
+    }
+
     /// The `subarray()` method stores multiple values in the typed array,
     /// reading input values from a specified array.
     pub fn subarray(&self, begin: u32, end: u32) -> Self {
@@ -150,16 +1327,173 @@ impl TypedArray {
     /// The `slice()` method returns a shallow copy of a portion of a typed
     /// array into a new typed array object. This method has the same algorithm
     /// as `Array.prototype.slice()`.
+    ///
+    /// Javascript allocates the copy, so the limit set with
+    /// [`set_allocation_limit`] does not apply to it.
+    ///
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
     pub fn slice(&self, begin: u32, end: u32) -> Self {
         match_every!(self, i, i.slice(begin, end).into())
     }
 
+    /// Copies all elements into a new typed array of the same kind, with its
+    /// own buffer. This is unlike `clone`, which returns another handle to the
+    /// same typed array. Like [`slice`], it ignores the allocation limit.
+    ///
+    /// [`slice`]: #method.slice
+    pub fn duplicate(&self) -> Self {
+        self.slice(0, self.length())
+    }
+
+    /// The same as [`subarray`], but the view extends to the end of the typed
+    /// array. The end is left to Javascript, so the length is not read.
+    ///
+    /// [`subarray`]: #method.subarray
+    pub fn subarray_from(&self, begin: u32) -> Self {
+        self.same_kind(self.as_any().subarray_from(begin))
+    }
+
+    /// The same as [`slice`], but the copy extends to the end of the typed
+    /// array. The end is left to Javascript, so the length is not read.
+    ///
+    /// [`slice`]: #method.slice
+    pub fn slice_from(&self, begin: u32) -> Self {
+        self.same_kind(self.as_any().slice_from(begin))
+    }
+
+    /// Divides the typed array into two views at an index: the first contains
+    /// the elements before `mid` and the second the elements from `mid`. Both
+    /// share the buffer of this typed array.
+    ///
+    /// # Panics
+    /// Panics if `mid` is greater than the length.
+    pub fn split_at(&self, mid: u32) -> (Self, Self) {
+        assert!(mid <= self.length(), "mid is out of bounds");
+        (self.subarray(0, mid), self.subarray_from(mid))
+    }
+
+    /// Returns a copy of the elements from `at` in a new buffer. Unlike
+    /// `Vec::split_off`, this typed array is left untouched, since its length
+    /// can't change. Unlike [`split_at`], the result does not share memory
+    /// with this typed array, so it can be transferred to a worker while this
+    /// typed array is still used.
+    ///
+    /// # Panics
+    /// Panics if `at` is greater than the length.
+    ///
+    /// [`split_at`]: #method.split_at
+    pub fn split_off(&self, at: u32) -> Self {
+        assert!(at <= self.length(), "at is out of bounds");
+        self.slice_from(at)
+    }
+
+    /// Returns an iterator over views of `chunk_len` elements of the typed
+    /// array, made with [`subarray`]. The last view is shorter if the length
+    /// is not a multiple of `chunk_len`.
+    ///
+    /// # Panics
+    /// Panics if `chunk_len` is zero.
+    ///
+    /// [`subarray`]: #method.subarray
+    pub fn chunks(&self, chunk_len: u32) -> Chunks<'_> {
+        Chunks::new(self, chunk_len)
+    }
+
+    /// Returns an iterator over all overlapping views of `size` elements of
+    /// the typed array, made with [`subarray`]. There are no windows if the
+    /// typed array is shorter than `size`.
+    ///
+    /// # Panics
+    /// Panics if `size` is zero.
+    ///
+    /// [`subarray`]: #method.subarray
+    pub fn windows(&self, size: u32) -> Windows<'_> {
+        Windows::new(self, size)
+    }
+
+    /// The same as [`subarray`], but returns an error instead of clamping if
+    /// `begin` is greater than `end` or `end` is greater than the length.
+    ///
+    /// [`subarray`]: #method.subarray
+    pub fn checked_subarray(&self, begin: u32, end: u32) -> Result<Self, RangeError> {
+        let length = self.length();
+        if begin <= end && end <= length {
+            Ok(self.subarray(begin, end))
+        } else {
+            Err(RangeError { begin, end, length })
+        }
+    }
+
+    /// The same as [`subarray`], but takes a Rust range. Unbounded ends are
+    /// resolved against the length of the typed array.
+    ///
+    /// [`subarray`]: #method.subarray
+    pub fn subarray_range(&self, range: impl RangeBounds<u32>) -> Self {
+        let (begin, end) = self.resolve_range(range);
+        self.subarray(begin, end)
+    }
+
+    /// The same as [`slice`], but takes a Rust range. Unbounded ends are
+    /// resolved against the length of the typed array.
+    ///
+    /// [`slice`]: #method.slice
+    pub fn slice_range(&self, range: impl RangeBounds<u32>) -> Self {
+        let (begin, end) = self.resolve_range(range);
+        self.slice(begin, end)
+    }
+
+    /// Converts a Rust range to the `begin` and `end` arguments of the
+    /// Javascript methods. The length is only read for an unbounded end.
+    fn resolve_range(&self, range: impl RangeBounds<u32>) -> (u32, u32) {
+        let begin = match range.start_bound() {
+            Bound::Included(&begin) => begin,
+            Bound::Excluded(&begin) => begin.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.saturating_add(1),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => self.length(),
+        };
+        (begin, end)
+    }
+
     /// The `length` accessor property represents the length (in elements) of a
     /// typed array.
+    ///
+    /// The length is read from Javascript on every call. It changes if the
+    /// typed array tracks the length of a resizable buffer, or becomes zero
+    /// if the buffer is detached or shrinks below the end of the typed array.
     pub fn length(&self) -> u32 {
         match_every!(self, i, i.length())
     }
 
+    /// Reads the current [`length`] once, as a bound for a loop over the
+    /// elements. Resizing the buffer during the loop does not change the
+    /// bound, but elements past the new end can no longer be read: they are
+    /// `NaN` for floating point kinds and zero otherwise.
+    ///
+    /// [`length`]: #method.length
+    pub fn snapshot_length(&self) -> u32 {
+        self.length()
+    }
+
+    /// Returns `true` if the typed array has a length of zero.
+    pub fn is_empty(&self) -> bool {
+        self.length() == 0
+    }
+
+    /// Returns the first element of the typed array, or `None` if it is
+    /// empty.
+    pub fn first(&self) -> Option<f64> {
+        match_every!(self, i, i.at(0).map(Element::to_f64))
+    }
+
+    /// Returns the last element of the typed array, or `None` if it is empty.
+    pub fn last(&self) -> Option<f64> {
+        match_every!(self, i, i.at(-1).map(Element::to_f64))
+    }
+
     /// The `byteLength` accessor property represents the length (in bytes) of a
     /// typed array.
     pub fn byte_length(&self) -> u32 {
@@ -178,6 +1512,296 @@ impl TypedArray {
         match_every!(self, i, i.set(src, offset))
     }
 
+    /// Gets the element at `index`, converted to `f64`. This is equivalent to
+    /// the Javascript `array[index]`.
+    pub fn get_index(&self, index: u32) -> f64 {
+        match_every!(self, i, i.get_index(index).to_f64())
+    }
+
+    /// Returns an iterator over the elements of the typed array, converted to
+    /// `f64`. Every element is read from Javascript as the iterator advances.
+    pub fn iter(&self) -> Iter<'_> {
+        Iter::new(self)
+    }
+
+    /// Returns chunks of at most `chunk_len` elements of the typed array,
+    /// converted to `f64`. Every chunk is copied from Javascript at once,
+    /// which makes this the fastest way to read a large typed array.
+    ///
+    /// # Panics
+    /// Panics if `chunk_len` is zero.
+    pub fn iter_chunks(&self, chunk_len: u32) -> IterChunks<'_> {
+        IterChunks::new(self, chunk_len)
+    }
+
+    /// The `fill()` method fills all the elements of a typed array from a
+    /// start index to an end index with a static value. The end index is not
+    /// included. A `Uint8ClampedArray` rounds the value half to even, like
+    /// Javascript does, before it is converted to the element type.
+    pub fn fill(&self, value: f64, start: u32, end: u32) -> &Self {
+        let value = self.round_for_storage(value);
+        match_every!(self, i, {
+            i.fill(Element::from_f64(value), start, end);
+        });
+        self
+    }
+
+    /// Fills the typed array with the values returned by `f`, which is called
+    /// with the index of every element. The values are generated in Rust and
+    /// copied into the typed array at once, which avoids a call into
+    /// Javascript for every element. A `Uint8ClampedArray` rounds them half to
+    /// even, like Javascript does.
+    pub fn fill_with(&self, mut f: impl FnMut(u32) -> f64) {
+        let length = self.length();
+        match_every!(self, i, {
+            let values: Vec<_> = (0..length)
+                .map(|index| Element::from_f64(self.round_for_storage(f(index))))
+                .collect();
+            i.copy_from(&values);
+        })
+    }
+
+    /// The `copyWithin()` method shallow copies part of a typed array to
+    /// another location in the same typed array, without modifying its size.
+    pub fn copy_within(&self, target: i32, start: i32, end: i32) -> &Self {
+        match_every!(self, i, {
+            i.copy_within(target, start, end);
+        });
+        self
+    }
+
+    /// Rotates the elements in place, so that the first `n` elements move to
+    /// the end and the element at index `n` becomes the first. `n` may be
+    /// larger than the length, in which case it wraps around. The shorter of
+    /// the two parts is copied into a temporary typed array, and the longer
+    /// is moved with [`copy_within`].
+    ///
+    /// [`copy_within`]: #method.copy_within
+    pub fn rotate_left(&self, n: u32) -> &Self {
+        let length = self.length();
+        if length == 0 {
+            return self;
+        }
+        let n = n % length;
+        if n <= length - n {
+            let head = self.slice(0, n);
+            self.copy_within(0, n as i32, length as i32);
+            self.set(head.as_ref(), length - n);
+        } else {
+            let tail = self.slice(n, length);
+            self.copy_within((length - n) as i32, 0, n as i32);
+            self.set(tail.as_ref(), 0);
+        }
+        self
+    }
+
+    /// Rotates the elements in place, so that the last `n` elements move to
+    /// the front. This is the reverse of [`rotate_left`].
+    ///
+    /// [`rotate_left`]: #method.rotate_left
+    pub fn rotate_right(&self, n: u32) -> &Self {
+        let length = self.length();
+        if length == 0 {
+            return self;
+        }
+        self.rotate_left(length - n % length)
+    }
+
+    /// The `reverse()` method reverses a typed array in place. The first
+    /// typed array element becomes the last and the last becomes the first.
+    pub fn reverse(&self) -> &Self {
+        self.as_any().reverse();
+        self
+    }
+
+    /// The `sort()` method sorts the elements of a typed array numerically in
+    /// place.
+    pub fn sort(&self) -> &Self {
+        self.as_any().sort();
+        self
+    }
+
+    /// Sorts the elements of a typed array in place, using `compare` to
+    /// determine the order of two elements. The comparator is passed to the
+    /// Javascript `sort()` method and is only borrowed for the duration of
+    /// the call.
+    pub fn sort_by(&self, mut compare: impl FnMut(f64, f64) -> Ordering) -> &Self {
+        self.as_any().sort_by(&mut |a, b| compare(a, b) as i32);
+        self
+    }
+
+    /// The `indexOf()` method returns the first index at which a given
+    /// element can be found in the typed array, searching from `from_index`,
+    /// or `None` if it is not present.
+    pub fn index_of(&self, value: f64, from_index: i32) -> Option<u32> {
+        found_index(self.as_any().index_of(value, from_index))
+    }
+
+    /// The `lastIndexOf()` method returns the last index at which a given
+    /// element can be found in the typed array, searching backwards from
+    /// `from_index`, or `None` if it is not present.
+    pub fn last_index_of(&self, value: f64, from_index: i32) -> Option<u32> {
+        found_index(self.as_any().last_index_of(value, from_index))
+    }
+
+    /// The `includes()` method determines whether a typed array includes a
+    /// certain element, searching from `from_index`.
+    pub fn includes(&self, value: f64, from_index: i32) -> bool {
+        self.as_any().includes(value, from_index)
+    }
+
+    /// The `find()` method returns the first element in the typed array that
+    /// satisfies the provided predicate, or `None` if there is no such
+    /// element. The predicate is called with every element and its index.
+    pub fn find(&self, mut predicate: impl FnMut(f64, u32) -> bool) -> Option<f64> {
+        self.as_any().find(&mut predicate).as_f64()
+    }
+
+    /// The `findIndex()` method returns the index of the first element in the
+    /// typed array that satisfies the provided predicate, or `None` if there
+    /// is no such element.
+    pub fn find_index(&self, mut predicate: impl FnMut(f64, u32) -> bool) -> Option<u32> {
+        found_index(self.as_any().find_index(&mut predicate))
+    }
+
+    /// The `every()` method tests whether all elements in the typed array pass
+    /// the provided predicate.
+    pub fn every(&self, mut predicate: impl FnMut(f64, u32) -> bool) -> bool {
+        self.as_any().every(&mut predicate)
+    }
+
+    /// The `some()` method tests whether some element in the typed array
+    /// passes the provided predicate.
+    pub fn some(&self, mut predicate: impl FnMut(f64, u32) -> bool) -> bool {
+        self.as_any().some(&mut predicate)
+    }
+
+    /// The `join()` method joins all elements of a typed array into a string,
+    /// separated by `separator`.
+    pub fn join(&self, separator: &str) -> String {
+        self.as_any().join(separator).into()
+    }
+
+    /// The `toLocaleString()` method returns a string representing the
+    /// elements of the typed array, formatted with the given locales and
+    /// options.
+    pub fn to_locale_string(&self, locales: &JsValue, options: &JsValue) -> JsString {
+        self.as_any().to_locale_string(locales, options)
+    }
+
+    /// The `forEach()` method calls `f` once for every element of the typed
+    /// array, with the element and its index.
+    pub fn for_each(&self, mut f: impl FnMut(f64, u32)) {
+        self.as_any().for_each(&mut f)
+    }
+
+    /// Creates a new typed array of the same kind, with every element
+    /// replaced by the result of calling `f` with the element and its index.
+    /// The elements are copied into Rust, transformed and copied back at once,
+    /// so `f` is not called across the Javascript boundary. The result is
+    /// never larger than this typed array, and is not checked against the
+    /// limit set with [`set_allocation_limit`].
+    ///
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    pub fn map(&self, mut f: impl FnMut(f64, u32) -> f64) -> Self {
+        match_every!(self, i: Array, {
+            let values: Vec<_> = (0..)
+                .zip(i.to_vec())
+                .map(|(index, x)| Element::from_f64(self.round_for_storage(f(x.to_f64(), index))))
+                .collect();
+            Array::from(&values[..]).into()
+        })
+    }
+
+    /// Creates a new typed array of the same kind, containing only the
+    /// elements for which `predicate` returns `true`. Like [`map`], the
+    /// predicate is evaluated in Rust on a copy of the elements, and the
+    /// allocation limit does not apply.
+    ///
+    /// [`map`]: #method.map
+    pub fn filter(&self, mut predicate: impl FnMut(f64, u32) -> bool) -> Self {
+        match_every!(self, i: Array, {
+            let values: Vec<_> = (0..)
+                .zip(i.to_vec())
+                .filter(|&(index, x)| predicate(x.to_f64(), index))
+                .map(|(_, x)| x)
+                .collect();
+            Array::from(&values[..]).into()
+        })
+    }
+
+    /// Folds every element of the typed array into an accumulator, from left
+    /// to right. `f` is called with the accumulator, the element and its
+    /// index. The elements are copied into Rust in chunks, so `f` is not
+    /// called across the Javascript boundary.
+    pub fn reduce<A>(&self, init: A, mut f: impl FnMut(A, f64, u32) -> A) -> A {
+        let mut accumulator = init;
+        let mut index = 0;
+        let mut chunks = self.iter_chunks(CHUNK_LEN);
+        while let Some(chunk) = chunks.next_chunk() {
+            for &x in chunk {
+                accumulator = f(accumulator, x, index);
+                index += 1;
+            }
+        }
+        accumulator
+    }
+
+    /// Folds every element of the typed array into an accumulator, from right
+    /// to left. See [`reduce`].
+    ///
+    /// [`reduce`]: #method.reduce
+    pub fn reduce_right<A>(&self, init: A, mut f: impl FnMut(A, f64, u32) -> A) -> A {
+        let mut accumulator = init;
+        let mut buffer = Vec::new();
+        for (begin, end) in chunk_ranges(self.length(), CHUNK_LEN).rev() {
+            self.copy_range_into(begin, end, &mut buffer);
+            for (index, &x) in (begin..end).zip(&buffer).rev() {
+                accumulator = f(accumulator, x, index);
+            }
+        }
+        accumulator
+    }
+
+    /// The `entries()` method returns a Javascript iterator that contains
+    /// the key/value pairs for each index in the typed array.
+    pub fn entries(&self) -> js_sys::Iterator {
+        self.as_any().entries()
+    }
+
+    /// The `keys()` method returns a Javascript iterator that contains the
+    /// keys for each index in the typed array.
+    pub fn keys(&self) -> js_sys::Iterator {
+        self.as_any().keys()
+    }
+
+    /// The `values()` method returns a Javascript iterator that contains the
+    /// values for each index in the typed array.
+    pub fn values(&self) -> js_sys::Iterator {
+        self.as_any().values()
+    }
+
+    /// Replaces the contents of `buffer` with the elements from `begin` to
+    /// `end`, converted to `f64`.
+    pub(crate) fn copy_range_into(&self, begin: u32, end: u32, buffer: &mut Vec<f64>) {
+        buffer.clear();
+        match_every!(self, i, {
+            let values = i.subarray(begin, end).to_vec();
+            buffer.extend(values.into_iter().map(Element::to_f64));
+        })
+    }
+
+    /// Wraps a typed array returned by a binding in `sys`, which is known to
+    /// be of the same kind as `self`.
+    fn same_kind(&self, array: AnyTypedArray) -> Self {
+        match_every!(self, _i: Array, array.unchecked_into::<Array>().into())
+    }
+
+    /// View this typed array as the kind-agnostic binding in `sys`.
+    fn as_any(&self) -> &AnyTypedArray {
+        AsRef::<JsValue>::as_ref(self).unchecked_ref()
+    }
+
     /// Tests whether the provided value is a typed array.
     pub fn has_type(i: JsValue) -> bool {
         i.has_type::<Int8Array>()
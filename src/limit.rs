@@ -0,0 +1,82 @@
+//! An optional limit on the size of the buffers allocated by this crate.
+
+use core::cell::Cell;
+use err_derive::*;
+
+thread_local! {
+    static ALLOCATION_LIMIT: Cell<Option<u64>> = const { Cell::new(None) };
+}
+
+/// Returned when a typed array would be larger than the limit set with
+/// [`set_allocation_limit`].
+///
+/// [`set_allocation_limit`]: fn.set_allocation_limit.html
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error(
+    display = "allocating {} bytes exceeds the allocation limit of {} bytes",
+    requested,
+    limit
+)]
+pub struct AllocationLimitError {
+    requested: u64,
+    limit: u64,
+}
+
+impl AllocationLimitError {
+    /// The number of bytes that would have been allocated.
+    pub fn requested(&self) -> u64 {
+        self.requested
+    }
+
+    /// The allocation limit at the time of the request.
+    pub fn limit(&self) -> u64 {
+        self.limit
+    }
+}
+
+/// Limits the size in bytes of every buffer that this crate allocates on the
+/// current thread, or removes the limit if `limit` is `None`. There is no
+/// limit by default.
+///
+/// This protects against lengths from untrusted input that would allocate
+/// enough memory to crash the page. Constructors that can fail, such as
+/// [`try_new_with_length`], return an [`AllocationLimitError`] when a buffer
+/// would exceed the limit. Others, such as [`new_with_length`] and the methods
+/// that use it, panic instead. Typed arrays created by `js_sys` directly are
+/// not limited, and neither are copies that Javascript makes of an existing
+/// typed array, such as those of [`slice`], [`map`] and [`filter`].
+///
+/// [`try_new_with_length`]: enum.TypedArray.html#method.try_new_with_length
+/// [`new_with_length`]: enum.TypedArray.html#method.new_with_length
+/// [`slice`]: enum.TypedArray.html#method.slice
+/// [`map`]: enum.TypedArray.html#method.map
+/// [`filter`]: enum.TypedArray.html#method.filter
+/// [`AllocationLimitError`]: struct.AllocationLimitError.html
+pub fn set_allocation_limit(limit: Option<u64>) {
+    ALLOCATION_LIMIT.with(|cell| cell.set(limit));
+}
+
+/// The limit set with [`set_allocation_limit`] on the current thread.
+///
+/// [`set_allocation_limit`]: fn.set_allocation_limit.html
+pub fn allocation_limit() -> Option<u64> {
+    ALLOCATION_LIMIT.with(Cell::get)
+}
+
+/// The same as `check_allocation`, but panics if the limit is exceeded.
+pub(crate) fn assert_allocation(byte_length: u64) {
+    if let Err(error) = check_allocation(byte_length) {
+        panic!("{}", error);
+    }
+}
+
+/// Checks that a buffer of `byte_length` bytes may be allocated.
+pub(crate) fn check_allocation(byte_length: u64) -> Result<(), AllocationLimitError> {
+    match allocation_limit() {
+        Some(limit) if byte_length > limit => Err(AllocationLimitError {
+            requested: byte_length,
+            limit,
+        }),
+        _ => Ok(()),
+    }
+}
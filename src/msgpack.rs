@@ -0,0 +1,85 @@
+//! Encoding typed arrays as MessagePack extension types.
+
+use crate::wire::{self, DecodeError};
+use crate::{TypedArray, TypedArrayKind};
+use core::convert::TryFrom;
+
+impl TypedArray {
+    /// Encodes this typed array as a MessagePack extension type, with the
+    /// elements in little endian byte order. The extension type code is the
+    /// same number as the CBOR typed array tag of RFC 8746 for the kind, from
+    /// 64 to 87, so peers can reuse the same table of element types.
+    ///
+    /// This method requires the `msgpack` feature.
+    ///
+    /// # Panics
+    /// Panics if the typed array is larger than 4 GiB, which MessagePack
+    /// cannot represent.
+    pub fn to_msgpack(&self) -> Vec<u8> {
+        let bytes = self.as_bytes().to_vec();
+        let mut msgpack = Vec::with_capacity(bytes.len() + 6);
+        let length = bytes.len();
+        if let Ok(length) = u8::try_from(length) {
+            msgpack.push(0xc7);
+            msgpack.push(length);
+        } else if let Ok(length) = u16::try_from(length) {
+            msgpack.push(0xc8);
+            msgpack.extend_from_slice(&length.to_be_bytes());
+        } else {
+            let length = u32::try_from(length).expect("typed array is too large for MessagePack");
+            msgpack.push(0xc9);
+            msgpack.extend_from_slice(&length.to_be_bytes());
+        }
+        msgpack.push(wire::cbor_tag(self.kind()));
+        msgpack.extend_from_slice(&bytes);
+        msgpack
+    }
+
+    /// Decodes a typed array encoded by [`to_msgpack`]. Extension types that
+    /// use a big endian CBOR tag are supported as well, and MessagePack binary
+    /// data is decoded as a `Uint8Array`.
+    ///
+    /// This method requires the `msgpack` feature.
+    ///
+    /// Returns an error if the typed array would exceed the limit set with
+    /// [`set_allocation_limit`].
+    ///
+    /// [`to_msgpack`]: #method.to_msgpack
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    pub fn from_msgpack(msgpack: &[u8]) -> Result<Self, DecodeError> {
+        let error = |position| DecodeError::new(position);
+        let format = *msgpack.first().ok_or_else(|| error(0))?;
+        // The size of the length, or the fixed length for `fixext`, and
+        // whether the data has an extension type.
+        let (length_size, fixed_length, extension) = match format {
+            0xc4 => (1, 0, false),
+            0xc5 => (2, 0, false),
+            0xc6 => (4, 0, false),
+            0xc7 => (1, 0, true),
+            0xc8 => (2, 0, true),
+            0xc9 => (4, 0, true),
+            0xd4..=0xd8 => (0, 1 << (format - 0xd4), true),
+            _ => return Err(error(0)),
+        };
+        let length = msgpack
+            .get(1..1 + length_size)
+            .ok_or_else(|| error(1))?
+            .iter()
+            .fold(fixed_length, |length, &byte| {
+                length << 8 | usize::from(byte)
+            });
+        let mut position = 1 + length_size;
+        let (kind, big_endian) = if extension {
+            let code = *msgpack.get(position).ok_or_else(|| error(position))?;
+            position += 1;
+            wire::kind_of_cbor_tag(u64::from(code)).ok_or_else(|| error(position - 1))?
+        } else {
+            (TypedArrayKind::Uint8Array, false)
+        };
+        let bytes = msgpack
+            .get(position..)
+            .filter(|bytes| bytes.len() == length)
+            .ok_or_else(|| error(position))?;
+        wire::from_element_bytes(kind, bytes, big_endian, position)
+    }
+}
@@ -0,0 +1,168 @@
+//! Reading and writing the `.npy` format of NumPy.
+
+use crate::wire::{self, DecodeError};
+use crate::{TypedArray, TypedArrayKind};
+use core::convert::TryFrom;
+
+/// The first bytes of every `.npy` file.
+const MAGIC: &[u8] = b"\x93NUMPY";
+
+impl TypedArray {
+    /// Writes this typed array in version 1.0 of the `.npy` format, as a one
+    /// dimensional array with little endian elements. A `Uint8ClampedArray`
+    /// is written with the same data type as a `Uint8Array`.
+    ///
+    /// This method requires the `npy` feature.
+    pub fn to_npy_bytes(&self) -> Vec<u8> {
+        let mut header = format!(
+            "{{'descr': '{}', 'fortran_order': False, 'shape': ({},), }}",
+            descr(self.kind()),
+            self.length()
+        );
+        // The data starts at a multiple of 64 bytes, after a newline.
+        let prefix_len = MAGIC.len() + 4;
+        let padding = 63 - (prefix_len + header.len()) % 64;
+        header.extend(core::iter::repeat(' ').take(padding));
+        header.push('\n');
+        let mut npy = Vec::with_capacity(prefix_len + header.len() + self.byte_length() as usize);
+        npy.extend_from_slice(MAGIC);
+        npy.extend_from_slice(&[1, 0]);
+        npy.extend_from_slice(&(header.len() as u16).to_le_bytes());
+        npy.extend_from_slice(header.as_bytes());
+        npy.extend_from_slice(&self.as_bytes().to_vec());
+        npy
+    }
+
+    /// Reads an array in the `.npy` format into a new typed array. Arrays with
+    /// more than one dimension are flattened, which requires them to be
+    /// stored in C order. The data type must be an integer of at most 32
+    /// bits or a 32 or 64 bit float, in either byte order.
+    ///
+    /// This method requires the `npy` feature.
+    ///
+    /// Returns an error if the typed array would exceed the limit set with
+    /// [`set_allocation_limit`].
+    ///
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    pub fn from_npy_bytes(npy: &[u8]) -> Result<Self, DecodeError> {
+        if !npy.starts_with(MAGIC) {
+            return Err(DecodeError::new(0));
+        }
+        let version = MAGIC.len();
+        let (header_start, header_len) = match npy.get(version) {
+            Some(1) => (
+                version + 4,
+                npy.get(version + 2..version + 4).map(little_endian),
+            ),
+            Some(2) | Some(3) => (
+                version + 6,
+                npy.get(version + 2..version + 6).map(little_endian),
+            ),
+            _ => return Err(DecodeError::new(version)),
+        };
+        let header_len = header_len.ok_or_else(|| DecodeError::new(version + 2))?;
+        let data_start = header_start
+            .checked_add(header_len)
+            .ok_or_else(|| DecodeError::new(version + 2))?;
+        let header = npy
+            .get(header_start..data_start)
+            .and_then(|header| core::str::from_utf8(header).ok())
+            .ok_or_else(|| DecodeError::new(header_start))?;
+        let error = || DecodeError::new(header_start);
+        let descr = dict_value(header, "descr").ok_or_else(error)?;
+        let (kind, big_endian) = kind_of_descr(descr.trim_matches('\'')).ok_or_else(error)?;
+        let fortran_order = match dict_value(header, "fortran_order") {
+            Some("False") => false,
+            Some("True") => true,
+            _ => return Err(error()),
+        };
+        let shape = dict_value(header, "shape")
+            .and_then(|shape| shape.strip_prefix('('))
+            .and_then(|shape| shape.strip_suffix(')'))
+            .ok_or_else(error)?;
+        let dimensions = shape
+            .split(',')
+            .map(str::trim)
+            .filter(|dimension| !dimension.is_empty())
+            .map(|dimension| dimension.parse::<u64>().map_err(|_| error()))
+            .collect::<Result<Vec<u64>, DecodeError>>()?;
+        if fortran_order && dimensions.len() > 1 {
+            return Err(error());
+        }
+        let length = dimensions
+            .iter()
+            .try_fold(1u64, |length, &dimension| length.checked_mul(dimension))
+            .ok_or_else(error)?;
+        let byte_length = length
+            .checked_mul(u64::from(kind.bytes_per_element()))
+            .and_then(|byte_length| usize::try_from(byte_length).ok())
+            .ok_or_else(error)?;
+        let data = npy
+            .get(data_start..)
+            .filter(|data| data.len() == byte_length)
+            .ok_or_else(|| DecodeError::new(data_start))?;
+        wire::from_element_bytes(kind, data, big_endian, data_start)
+    }
+}
+
+/// Reads an unsigned little endian integer.
+fn little_endian(bytes: &[u8]) -> usize {
+    bytes
+        .iter()
+        .rev()
+        .fold(0, |value, &byte| value << 8 | usize::from(byte))
+}
+
+/// The NumPy data type of a kind, with little endian elements.
+fn descr(kind: TypedArrayKind) -> &'static str {
+    match kind {
+        TypedArrayKind::Int8Array => "|i1",
+        TypedArrayKind::Uint8Array | TypedArrayKind::Uint8ClampedArray => "|u1",
+        TypedArrayKind::Int16Array => "<i2",
+        TypedArrayKind::Uint16Array => "<u2",
+        TypedArrayKind::Int32Array => "<i4",
+        TypedArrayKind::Uint32Array => "<u4",
+        TypedArrayKind::Float32Array => "<f4",
+        TypedArrayKind::Float64Array => "<f8",
+    }
+}
+
+/// The kind, and whether the elements are big endian, of a NumPy data type.
+fn kind_of_descr(descr: &str) -> Option<(TypedArrayKind, bool)> {
+    let (order, data_type) = descr.split_at(descr.len().min(1));
+    let big_endian = match order {
+        "<" | "|" | "=" => false,
+        ">" => true,
+        _ => return None,
+    };
+    let kind = match data_type {
+        "i1" | "b" => TypedArrayKind::Int8Array,
+        "u1" | "B" => TypedArrayKind::Uint8Array,
+        "i2" => TypedArrayKind::Int16Array,
+        "u2" => TypedArrayKind::Uint16Array,
+        "i4" => TypedArrayKind::Int32Array,
+        "u4" => TypedArrayKind::Uint32Array,
+        "f4" => TypedArrayKind::Float32Array,
+        "f8" => TypedArrayKind::Float64Array,
+        _ => return None,
+    };
+    Some((kind, big_endian))
+}
+
+/// Finds the value of a key in the Python dictionary literal of a header. The
+/// value ends at the next comma that is not inside parentheses.
+fn dict_value<'a>(header: &'a str, key: &str) -> Option<&'a str> {
+    let start = header.find(&format!("'{}'", key))? + key.len() + 2;
+    let rest = header[start..].trim_start().strip_prefix(':')?.trim_start();
+    let mut depth = 0;
+    let end = rest.char_indices().find_map(|(index, c)| {
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            ',' | '}' if depth == 0 => return Some(index),
+            _ => {}
+        }
+        None
+    })?;
+    Some(rest[..end].trim())
+}
@@ -0,0 +1,69 @@
+//! Locating and ranking the values of typed arrays.
+
+use crate::search::sort_order;
+use crate::{limit, TypedArray, CHUNK_LEN};
+use core::cmp::Ordering;
+use js_sys::Uint32Array;
+
+impl TypedArray {
+    /// The index of the smallest value, or `None` if there are no values. If
+    /// the smallest value occurs several times, the first index is returned.
+    /// `NaN` is skipped. The elements are copied into WebAssembly memory in
+    /// chunks.
+    pub fn argmin(&self) -> Option<u32> {
+        self.find_extreme(Ordering::Less)
+    }
+
+    /// The index of the largest value, or `None` if there are no values. If
+    /// the largest value occurs several times, the first index is returned.
+    /// `NaN` is skipped. The elements are copied into WebAssembly memory in
+    /// chunks.
+    pub fn argmax(&self) -> Option<u32> {
+        self.find_extreme(Ordering::Greater)
+    }
+
+    /// The permutation that sorts this typed array: the index of the smallest
+    /// element first and that of the largest last. Values are ordered the
+    /// same way as by [`sort`], with `NaN` last, and the sort is stable, so
+    /// equal values keep their order. The typed array itself is not changed.
+    /// Passing the result to [`gather`] creates a sorted copy.
+    ///
+    /// # Panics
+    /// Panics if the typed array would exceed the limit set with
+    /// [`set_allocation_limit`].
+    ///
+    /// [`sort`]: #method.sort
+    /// [`gather`]: #method.gather
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    pub fn argsort(&self) -> Uint32Array {
+        let length = self.length();
+        limit::assert_allocation(u64::from(length) * 4);
+        let mut elements = Vec::new();
+        self.copy_range_into(0, length, &mut elements);
+        let mut indices: Vec<u32> = (0..length).collect();
+        indices.sort_by(|&a, &b| sort_order(elements[a as usize], elements[b as usize]));
+        Uint32Array::from(&indices[..])
+    }
+
+    /// The index of the first value that compares as `ordering` to all
+    /// others, skipping `NaN`.
+    fn find_extreme(&self, ordering: Ordering) -> Option<u32> {
+        let mut extreme: Option<(u32, f64)> = None;
+        let mut index = 0;
+        let mut chunks = self.iter_chunks(CHUNK_LEN);
+        while let Some(chunk) = chunks.next_chunk() {
+            for &x in chunk {
+                let better = match extreme {
+                    _ if x.is_nan() => false,
+                    Some((_, value)) => x.partial_cmp(&value) == Some(ordering),
+                    None => true,
+                };
+                if better {
+                    extreme = Some((index, x));
+                }
+                index += 1;
+            }
+        }
+        extreme.map(|(index, _)| index)
+    }
+}
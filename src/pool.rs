@@ -0,0 +1,129 @@
+//! Recycling the buffers of typed arrays.
+
+use crate::{limit, TypedArray, TypedArrayKind};
+use core::cell::RefCell;
+use core::convert::TryFrom;
+use core::ops::Deref;
+use js_sys::ArrayBuffer;
+use std::rc::{Rc, Weak};
+
+/// The smallest buffer that the pool allocates, in bytes.
+const MIN_CAPACITY: u64 = 8;
+
+/// Unused buffers, indexed by the base 2 logarithm of their byte length.
+type FreeLists = RefCell<Vec<Vec<ArrayBuffer>>>;
+
+/// A pool of buffers that hands out typed arrays and takes their buffers back
+/// when they are dropped, so that loops that need temporary typed arrays
+/// don't allocate new buffers for the garbage collector to clean up.
+///
+/// Buffers are allocated with a byte length that is a power of two, and are
+/// reused for any typed array that fits. Cloning the pool returns another
+/// handle to the same buffers.
+#[derive(Clone, Debug, Default)]
+pub struct TypedArrayPool {
+    free: Rc<FreeLists>,
+}
+
+impl TypedArrayPool {
+    /// Creates an empty pool.
+    pub fn new() -> Self {
+        TypedArrayPool::default()
+    }
+
+    /// Returns a typed array of the given kind and length, with all elements
+    /// zero. Its buffer is reused from an earlier typed array if possible,
+    /// and returned to the pool when the `PooledTypedArray` is dropped.
+    ///
+    /// The buffer may be larger than the typed array, so [`buffer`] should
+    /// not be used to access its elements.
+    ///
+    /// # Panics
+    /// Panics if the typed array would be larger than 2 GiB, or if a new
+    /// buffer would exceed the limit set with [`set_allocation_limit`].
+    ///
+    /// [`buffer`]: enum.TypedArray.html#method.buffer
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    pub fn acquire(&self, kind: TypedArrayKind, length: u32) -> PooledTypedArray {
+        let byte_length = u64::from(length) * u64::from(kind.bytes_per_element());
+        let capacity = byte_length.next_power_of_two().max(MIN_CAPACITY);
+        let bucket = capacity.trailing_zeros() as usize;
+        let reused = self.free.borrow_mut().get_mut(bucket).and_then(Vec::pop);
+        let array = match reused {
+            Some(buffer) => {
+                let array = TypedArray::new_with_buffer(kind, &buffer, 0, length);
+                array.fill(0.0, 0, length);
+                array
+            }
+            None => {
+                limit::assert_allocation(capacity);
+                let capacity = u32::try_from(capacity).expect("pooled typed array is too large");
+                let buffer = ArrayBuffer::new(capacity);
+                TypedArray::new_with_buffer(kind, &buffer, 0, length)
+            }
+        };
+        PooledTypedArray {
+            array,
+            bucket,
+            pool: Rc::downgrade(&self.free),
+        }
+    }
+
+    /// The number of buffers that are ready to be reused.
+    pub fn free_buffers(&self) -> usize {
+        self.free.borrow().iter().map(Vec::len).sum()
+    }
+
+    /// Drops all buffers that are ready to be reused, so that the garbage
+    /// collector can clean them up.
+    pub fn clear(&self) {
+        self.free.borrow_mut().clear();
+    }
+}
+
+/// A typed array handed out by a [`TypedArrayPool`]. It dereferences to a
+/// `TypedArray`, and returns its buffer to the pool when it is dropped.
+///
+/// Clones of the typed array must not be used after that, because the buffer
+/// will be handed out again. A buffer that has been detached, for example by
+/// transferring it, is not returned to the pool.
+///
+/// [`TypedArrayPool`]: struct.TypedArrayPool.html
+#[derive(Debug)]
+pub struct PooledTypedArray {
+    array: TypedArray,
+    bucket: usize,
+    pool: Weak<FreeLists>,
+}
+
+impl PooledTypedArray {
+    /// Takes the typed array out of the pool. Its buffer is not returned to
+    /// the pool, so the typed array can be kept.
+    pub fn into_inner(self) -> TypedArray {
+        let mut pooled = self;
+        pooled.pool = Weak::new();
+        pooled.array.clone()
+    }
+}
+
+impl Deref for PooledTypedArray {
+    type Target = TypedArray;
+
+    fn deref(&self) -> &TypedArray {
+        &self.array
+    }
+}
+
+impl Drop for PooledTypedArray {
+    fn drop(&mut self) {
+        if let Some(free) = self.pool.upgrade() {
+            if !self.array.is_detached() {
+                let mut free = free.borrow_mut();
+                if free.len() <= self.bucket {
+                    free.resize_with(self.bucket + 1, Vec::new);
+                }
+                free[self.bucket].push(self.array.buffer());
+            }
+        }
+    }
+}
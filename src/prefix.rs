@@ -0,0 +1,58 @@
+//! Cumulative operations over the elements of typed arrays.
+
+use crate::{chunk_ranges, Element, TypedArray, CHUNK_LEN};
+
+impl TypedArray {
+    /// Creates a new typed array of the same kind, in which every element is
+    /// the sum of the elements of this typed array up to and including it.
+    /// See [`cumsum_in_place`].
+    ///
+    /// [`cumsum_in_place`]: #method.cumsum_in_place
+    pub fn cumsum(&self) -> Self {
+        let result = self.duplicate();
+        result.cumsum_in_place();
+        result
+    }
+
+    /// Replaces every element with the sum of the elements up to and
+    /// including it, for example to turn the lengths of records into their
+    /// end offsets. The sums are computed in `f64` and converted to the
+    /// element type the same way as the results of [`scale_offset`], so for
+    /// integer kinds they saturate at the limits of the type.
+    ///
+    /// [`scale_offset`]: #method.scale_offset
+    pub fn cumsum_in_place(&self) {
+        self.prefix_scan(|sum, x| sum + x);
+    }
+
+    /// Replaces every element after the first with the result of `f` on the
+    /// new value of the previous element and the element itself, from the
+    /// first element to the last. With addition this is [`cumsum_in_place`],
+    /// and with `f64::max` it gives the running maximum. The elements are
+    /// copied into WebAssembly memory in chunks, transformed, and copied back.
+    ///
+    /// Results are converted to the element type the same way as those of
+    /// [`scale_offset`], and that converted value is passed on to the next
+    /// call.
+    ///
+    /// [`cumsum_in_place`]: #method.cumsum_in_place
+    /// [`scale_offset`]: #method.scale_offset
+    pub fn prefix_scan(&self, mut f: impl FnMut(f64, f64) -> f64) {
+        let length = self.length();
+        match_every!(self, array, {
+            let mut previous = None;
+            for (begin, end) in chunk_ranges(length, CHUNK_LEN) {
+                let chunk = array.subarray(begin, end);
+                let mut values = chunk.to_vec();
+                for value in &mut values {
+                    if let Some(previous) = previous {
+                        *value =
+                            Element::from_f64(self.round_for_storage(f(previous, value.to_f64())));
+                    }
+                    previous = Some(value.to_f64());
+                }
+                chunk.copy_from(&values);
+            }
+        })
+    }
+}
@@ -0,0 +1,37 @@
+//! Passing typed arrays through `serde_wasm_bindgen` as the Javascript typed
+//! array objects themselves.
+//!
+//! Use this module with `#[serde(with = "typed_array::preserve")]` on a field
+//! of type `TypedArray`. `serde_wasm_bindgen::to_value` then puts the typed
+//! array object in the resulting Javascript value, sharing its buffer, and
+//! `serde_wasm_bindgen::from_value` takes a typed array object of any kind out
+//! of it. Nothing is copied, so the result can be transferred or structured
+//! cloned like any other typed array. This is `serde_wasm_bindgen::preserve`
+//! for `TypedArray`, which does not implement `JsCast`.
+//!
+//! Other formats cannot represent a Javascript object, so they fail to
+//! deserialize the field. Use the `Serialize` implementation of `TypedArray`
+//! for those instead.
+//!
+//! This module requires the `serde-wasm-bindgen` feature.
+
+use crate::TypedArray;
+use core::convert::TryFrom;
+use serde::de::{Deserializer, Error, Unexpected};
+use serde::ser::Serializer;
+use wasm_bindgen::JsValue;
+
+/// Serializes the typed array object with `serde_wasm_bindgen::preserve`.
+pub fn serialize<S: Serializer>(array: &TypedArray, serializer: S) -> Result<S::Ok, S::Error> {
+    serde_wasm_bindgen::preserve::serialize(AsRef::<JsValue>::as_ref(array), serializer)
+}
+
+/// Deserializes a typed array object with `serde_wasm_bindgen::preserve`.
+/// Returns an error if the Javascript value is not a typed array, or if the
+/// deserializer is not the one of `serde_wasm_bindgen`.
+pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<TypedArray, D::Error> {
+    let value: JsValue = serde_wasm_bindgen::preserve::deserialize(deserializer)?;
+    TypedArray::try_from(value).map_err(|_| {
+        D::Error::invalid_value(Unexpected::Other("a Javascript value"), &"a typed array")
+    })
+}
@@ -0,0 +1,41 @@
+//! Scanning floating point typed arrays for values that are not finite.
+
+use crate::{TypedArray, CHUNK_LEN};
+
+impl TypedArray {
+    /// Returns `true` if any element is `NaN`. Typed arrays of integer kinds
+    /// never contain `NaN`, so they are not scanned.
+    pub fn contains_nan(&self) -> bool {
+        self.position_of_float(f64::is_nan).is_some()
+    }
+
+    /// Returns `true` if any element is positive or negative infinity.
+    /// Typed arrays of integer kinds are not scanned.
+    pub fn contains_infinite(&self) -> bool {
+        self.position_of_float(f64::is_infinite).is_some()
+    }
+
+    /// Returns the index of the first element that is `NaN` or infinite, or
+    /// `None` if all elements are finite. Typed arrays of integer kinds are
+    /// not scanned.
+    pub fn find_first_non_finite(&self) -> Option<u32> {
+        self.position_of_float(|x| !x.is_finite())
+    }
+
+    /// Returns the index of the first element that satisfies `predicate` in
+    /// a floating point typed array, or `None` for integer kinds.
+    fn position_of_float(&self, predicate: impl Fn(f64) -> bool) -> Option<u32> {
+        if self.kind().integer_range().is_some() {
+            return None;
+        }
+        let mut index = 0;
+        let mut chunks = self.iter_chunks(CHUNK_LEN);
+        while let Some(chunk) = chunks.next_chunk() {
+            if let Some(position) = chunk.iter().position(|&x| predicate(x)) {
+                return Some(index + position as u32);
+            }
+            index += chunk.len() as u32;
+        }
+        None
+    }
+}
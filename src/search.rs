@@ -0,0 +1,67 @@
+//! Searching sorted typed arrays.
+
+use crate::TypedArray;
+use core::cmp::Ordering;
+
+impl TypedArray {
+    /// Searches this typed array, which must be sorted in ascending order, for
+    /// `value`. Returns `Ok` with the index of a matching element, or `Err`
+    /// with the index at which `value` could be inserted to keep the order.
+    /// If several elements match, any of them may be returned.
+    ///
+    /// Values are compared in the same order as [`sort`] uses: `-0.0` before
+    /// `0.0`, and every `NaN` after all numbers. This reads only the elements
+    /// it compares, with `O(log n)` calls into Javascript. See
+    /// [`binary_search_many`] to look up many values at once.
+    ///
+    /// [`sort`]: #method.sort
+    /// [`binary_search_many`]: #method.binary_search_many
+    pub fn binary_search(&self, value: f64) -> Result<u32, u32> {
+        binary_search_by(self.length(), |index| {
+            sort_order(self.get_index(index), value)
+        })
+    }
+
+    /// The same as [`binary_search`] for every value in `values`, but copies
+    /// the elements into WebAssembly memory once and searches there. This is
+    /// faster when the number of lookups is large compared to the logarithm
+    /// of the length.
+    ///
+    /// [`binary_search`]: #method.binary_search
+    pub fn binary_search_many(&self, values: &[f64]) -> Vec<Result<u32, u32>> {
+        let mut elements = Vec::new();
+        self.copy_range_into(0, self.length(), &mut elements);
+        values
+            .iter()
+            .map(|value| {
+                binary_search_by(elements.len() as u32, |index| {
+                    sort_order(elements[index as usize], *value)
+                })
+            })
+            .collect()
+    }
+}
+
+/// Compares two values in the order of `TypedArray.prototype.sort`, which is
+/// the total order of `f64::total_cmp` except that every `NaN` is larger than
+/// all numbers, whatever its sign.
+pub(crate) fn sort_order(a: f64, b: f64) -> Ordering {
+    let canonical = |x: f64| if x.is_nan() { f64::NAN } else { x };
+    canonical(a).total_cmp(&canonical(b))
+}
+
+/// Binary search over `length` elements, where `compare` compares the element
+/// at an index to the value that is searched for.
+fn binary_search_by(length: u32, mut compare: impl FnMut(u32) -> Ordering) -> Result<u32, u32> {
+    let mut low = 0;
+    let mut high = length;
+    while low < high {
+        let middle = low + (high - low) / 2;
+        match compare(middle) {
+            Ordering::Less => low = middle + 1,
+            Ordering::Greater => high = middle,
+            Ordering::Equal => return Ok(middle),
+        }
+    }
+    Err(low)
+}
@@ -0,0 +1,194 @@
+//! Serializing typed arrays with `serde`.
+
+use crate::{TypedArray, TypedArrayKind};
+use core::convert::TryFrom;
+use core::fmt;
+use serde::de::{self, Deserialize, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+
+const FIELDS: &[&str] = &["kind", "bytes"];
+
+const KINDS: [TypedArrayKind; 9] = [
+    TypedArrayKind::Int8Array,
+    TypedArrayKind::Uint8Array,
+    TypedArrayKind::Uint8ClampedArray,
+    TypedArrayKind::Int16Array,
+    TypedArrayKind::Uint16Array,
+    TypedArrayKind::Int32Array,
+    TypedArrayKind::Uint32Array,
+    TypedArrayKind::Float32Array,
+    TypedArrayKind::Float64Array,
+];
+
+const KIND_NAMES: &[&str] = &[
+    "Int8Array",
+    "Uint8Array",
+    "Uint8ClampedArray",
+    "Int16Array",
+    "Uint16Array",
+    "Int32Array",
+    "Uint32Array",
+    "Float32Array",
+    "Float64Array",
+];
+
+impl Serialize for TypedArrayKind {
+    /// Serializes the kind as the name of its Javascript constructor, like
+    /// `"Float32Array"`.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+impl<'de> Deserialize<'de> for TypedArrayKind {
+    /// Deserializes a kind from the name of its Javascript constructor.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(KindVisitor)
+    }
+}
+
+struct KindVisitor;
+
+impl<'de> Visitor<'de> for KindVisitor {
+    type Value = TypedArrayKind;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("the name of a typed array constructor")
+    }
+
+    fn visit_str<E: de::Error>(self, name: &str) -> Result<TypedArrayKind, E> {
+        KINDS
+            .iter()
+            .copied()
+            .find(|kind| kind.name() == name)
+            .ok_or_else(|| E::unknown_variant(name, KIND_NAMES))
+    }
+}
+
+impl Serialize for TypedArray {
+    /// Serializes the typed array as a struct with two fields: `kind`, the
+    /// constructor name like `"Float32Array"`, and `bytes`, the bytes of the
+    /// elements in the byte order of the platform. That is little endian on
+    /// practically every platform that runs WebAssembly.
+    ///
+    /// With `serde_wasm_bindgen`, this becomes a Javascript object like
+    /// `{ kind: "Float32Array", bytes: Uint8Array }`, so the elements are
+    /// copied into a new buffer. To pass the typed array object itself,
+    /// annotate the field with `#[serde(with = "typed_array::preserve")]`,
+    /// which requires the `serde-wasm-bindgen` feature.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("TypedArray", 2)?;
+        state.serialize_field("kind", &self.kind())?;
+        state.serialize_field("bytes", &Bytes(self.as_bytes().to_vec()))?;
+        state.end()
+    }
+}
+
+impl<'de> Deserialize<'de> for TypedArray {
+    /// Deserializes a typed array serialized by the `Serialize`
+    /// implementation, creating a new buffer for its elements.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_struct("TypedArray", FIELDS, TypedArrayVisitor)
+    }
+}
+
+struct TypedArrayVisitor;
+
+impl<'de> Visitor<'de> for TypedArrayVisitor {
+    type Value = TypedArray;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a typed array with a kind and bytes")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<TypedArray, A::Error> {
+        let kind = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(0, &self))?;
+        let Bytes(bytes) = seq
+            .next_element()?
+            .ok_or_else(|| de::Error::invalid_length(1, &self))?;
+        from_bytes(kind, &bytes)
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<TypedArray, A::Error> {
+        let mut kind = None;
+        let mut bytes = None;
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "kind" if kind.is_none() => kind = Some(map.next_value()?),
+                "bytes" if bytes.is_none() => bytes = Some(map.next_value::<Bytes>()?.0),
+                "kind" | "bytes" => {
+                    return Err(de::Error::custom(format!("duplicate field `{}`", key)))
+                }
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+        let kind = kind.ok_or_else(|| de::Error::missing_field("kind"))?;
+        let bytes = bytes.ok_or_else(|| de::Error::missing_field("bytes"))?;
+        from_bytes(kind, &bytes)
+    }
+}
+
+/// Creates a typed array of the given kind containing `bytes`.
+pub(crate) fn from_bytes<E: de::Error>(
+    kind: TypedArrayKind,
+    bytes: &[u8],
+) -> Result<TypedArray, E> {
+    let bytes_per_element = kind.bytes_per_element() as usize;
+    if bytes.len() % bytes_per_element != 0 {
+        return Err(E::invalid_length(
+            bytes.len(),
+            &"a multiple of the size of an element",
+        ));
+    }
+    let length = u32::try_from(bytes.len() / bytes_per_element)
+        .map_err(|_| E::custom("typed array is too long"))?;
+    let array = TypedArray::try_new_with_length(kind, length).map_err(E::custom)?;
+    array.as_bytes().copy_from(bytes);
+    Ok(array)
+}
+
+/// Bytes that are serialized with `serialize_bytes`, and can be deserialized
+/// from both bytes and a sequence of integers.
+struct Bytes(Vec<u8>);
+
+impl Serialize for Bytes {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Bytes {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_byte_buf(BytesVisitor)
+    }
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Bytes;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("bytes")
+    }
+
+    fn visit_bytes<E: de::Error>(self, bytes: &[u8]) -> Result<Bytes, E> {
+        Ok(Bytes(bytes.to_vec()))
+    }
+
+    fn visit_byte_buf<E: de::Error>(self, bytes: Vec<u8>) -> Result<Bytes, E> {
+        Ok(Bytes(bytes))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Bytes, A::Error> {
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(Bytes(bytes))
+    }
+}
@@ -0,0 +1,78 @@
+//! Handles to typed arrays in shared WebAssembly memory that can be sent
+//! between threads.
+
+use crate::{TypedArray, TypedArrayKind};
+use err_derive::*;
+use js_sys::WebAssembly::Memory;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// Returned when creating a `SharedTypedArray` from a typed array that does
+/// not view the shared memory of the WebAssembly module.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Default, Error)]
+#[error(display = "typed array does not view the shared memory of the WebAssembly module")]
+pub struct NotSharedMemoryError {
+    _priv: (),
+}
+
+/// A typed array in the shared memory of a threaded WebAssembly module, which
+/// can be sent between threads and stored in shared state.
+///
+/// A `TypedArray` can't be sent to another thread, because the handle to the
+/// Javascript object is only valid on the thread that created it. This type
+/// does not hold a handle. Instead, it remembers which part of the memory the
+/// typed array views, and [`view`] creates a new typed array for the current
+/// thread. Since every thread has the same memory, all of these typed arrays
+/// view the same bytes.
+///
+/// [`view`]: #method.view
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub struct SharedTypedArray {
+    kind: TypedArrayKind,
+    byte_offset: u32,
+    length: u32,
+}
+
+impl SharedTypedArray {
+    /// Creates a handle to the memory viewed by `array`, which must view the
+    /// `SharedArrayBuffer` of the memory of this WebAssembly module. The
+    /// memory is only shared if the module is compiled with support for
+    /// threads.
+    pub fn new(array: &TypedArray) -> Result<Self, NotSharedMemoryError> {
+        if array.is_shared() && js_sys::Object::is(array.buffer().as_ref(), &memory_buffer()) {
+            Ok(SharedTypedArray {
+                kind: array.kind(),
+                byte_offset: array.byte_offset(),
+                length: array.length(),
+            })
+        } else {
+            Err(NotSharedMemoryError::default())
+        }
+    }
+
+    /// Creates a typed array for the current thread that views the same
+    /// memory as the typed array this handle was created from.
+    pub fn view(&self) -> TypedArray {
+        TypedArray::new_with_buffer(self.kind, &memory_buffer(), self.byte_offset, self.length)
+    }
+
+    /// The kind of the typed array.
+    pub fn kind(&self) -> TypedArrayKind {
+        self.kind
+    }
+
+    /// The offset of the typed array in bytes from the start of the memory.
+    pub fn byte_offset(&self) -> u32 {
+        self.byte_offset
+    }
+
+    /// The length of the typed array in elements.
+    pub fn length(&self) -> u32 {
+        self.length
+    }
+}
+
+/// The buffer of the memory of this WebAssembly module. It is replaced by a
+/// larger one whenever the memory grows, so it must not be cached.
+fn memory_buffer() -> JsValue {
+    wasm_bindgen::memory().unchecked_into::<Memory>().buffer()
+}
@@ -0,0 +1,170 @@
+//! Statistics over the values of typed arrays.
+
+use crate::{TypedArray, CHUNK_LEN};
+
+/// How statistics such as [`min`] and [`sum`] treat `NaN` elements. Typed
+/// arrays of integer kinds never contain `NaN`, so the mode does not matter
+/// for them.
+///
+/// [`min`]: enum.TypedArray.html#method.min
+/// [`sum`]: enum.TypedArray.html#method.sum
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum NanMode {
+    /// A single `NaN` makes the result `NaN`, the same as the arithmetic of
+    /// floating point numbers.
+    Propagate,
+    /// `NaN` elements are skipped, as if they were not in the typed array.
+    Ignore,
+}
+
+impl TypedArray {
+    /// The smallest value, or `None` if there are no values. The elements are
+    /// copied into WebAssembly memory in chunks.
+    pub fn min(&self, nan: NanMode) -> Option<f64> {
+        self.fold_values(nan, None, |min: Option<f64>, x| {
+            Some(min.map_or(x, |min| min.min(x)))
+        })
+        .unwrap_or(Some(f64::NAN))
+    }
+
+    /// The largest value, or `None` if there are no values. The elements are
+    /// copied into WebAssembly memory in chunks.
+    pub fn max(&self, nan: NanMode) -> Option<f64> {
+        self.fold_values(nan, None, |max: Option<f64>, x| {
+            Some(max.map_or(x, |max| max.max(x)))
+        })
+        .unwrap_or(Some(f64::NAN))
+    }
+
+    /// The sum of the values, computed in `f64`, which is zero if there are no
+    /// values. The elements are copied into WebAssembly memory in chunks.
+    pub fn sum(&self, nan: NanMode) -> f64 {
+        self.fold_values(nan, 0.0, |sum, x| sum + x)
+            .unwrap_or(f64::NAN)
+    }
+
+    /// The arithmetic mean of the values, or `None` if there are no values.
+    /// The elements are copied into WebAssembly memory in chunks.
+    pub fn mean(&self, nan: NanMode) -> Option<f64> {
+        match self.fold_values(nan, (0.0, 0u32), |(sum, count), x| (sum + x, count + 1)) {
+            Some((_, 0)) => None,
+            Some((sum, count)) => Some(sum / f64::from(count)),
+            None => Some(f64::NAN),
+        }
+    }
+
+    /// The population variance of the values, or `None` if there are no
+    /// values. It is computed in a single pass with Welford's algorithm, which
+    /// is numerically stable, over chunks copied into WebAssembly memory.
+    pub fn variance(&self, nan: NanMode) -> Option<f64> {
+        match self.welford(nan) {
+            Some((0, _)) => None,
+            Some((count, m2)) => Some(m2 / f64::from(count)),
+            None => Some(f64::NAN),
+        }
+    }
+
+    /// The sample variance of the values, with Bessel's correction, or `None`
+    /// if there are fewer than two values. See [`variance`].
+    ///
+    /// [`variance`]: #method.variance
+    pub fn sample_variance(&self, nan: NanMode) -> Option<f64> {
+        match self.welford(nan) {
+            Some((0, _)) | Some((1, _)) => None,
+            Some((count, m2)) => Some(m2 / f64::from(count - 1)),
+            None => Some(f64::NAN),
+        }
+    }
+
+    /// The population standard deviation, the square root of [`variance`].
+    ///
+    /// [`variance`]: #method.variance
+    pub fn std_dev(&self, nan: NanMode) -> Option<f64> {
+        self.variance(nan).map(f64::sqrt)
+    }
+
+    /// The sample standard deviation, the square root of
+    /// [`sample_variance`].
+    ///
+    /// [`sample_variance`]: #method.sample_variance
+    pub fn sample_std_dev(&self, nan: NanMode) -> Option<f64> {
+        self.sample_variance(nan).map(f64::sqrt)
+    }
+
+    /// Counts the values in `bins` bins of equal width that span `range`,
+    /// which defaults to the range from the smallest to the largest value.
+    /// Every bin includes its lower edge, and the last also includes the upper
+    /// edge. Values outside of the range and `NaN` are not counted. If the
+    /// range is empty, it is widened by 0.5 on both sides.
+    ///
+    /// # Panics
+    /// Panics if `bins` is zero, or if `range` is not finite or its start is
+    /// greater than its end.
+    pub fn histogram(&self, bins: u32, range: Option<(f64, f64)>) -> Vec<u32> {
+        assert!(bins != 0, "histogram needs at least one bin");
+        let (mut low, mut high) = match range {
+            Some(range) => range,
+            None => (
+                self.min(NanMode::Ignore).unwrap_or(0.0),
+                self.max(NanMode::Ignore).unwrap_or(0.0),
+            ),
+        };
+        assert!(
+            low.is_finite() && high.is_finite() && low <= high,
+            "invalid histogram range"
+        );
+        if low == high {
+            low -= 0.5;
+            high += 0.5;
+        }
+        let scale = f64::from(bins) / (high - low);
+        let mut counts = vec![0; bins as usize];
+        let mut chunks = self.iter_chunks(CHUNK_LEN);
+        while let Some(chunk) = chunks.next_chunk() {
+            for &x in chunk {
+                // This is false for `NaN`.
+                if low <= x && x <= high {
+                    let bin = (((x - low) * scale) as u32).min(bins - 1);
+                    counts[bin as usize] += 1;
+                }
+            }
+        }
+        counts
+    }
+
+    /// Returns the number of values and the sum of the squares of their
+    /// differences from the mean, or `None` if the `NaN` is propagated.
+    fn welford(&self, nan: NanMode) -> Option<(u32, f64)> {
+        self.fold_values(nan, (0, 0.0, 0.0), |(count, mean, m2), x| {
+            let count = count + 1;
+            let delta = x - mean;
+            let mean = mean + delta / f64::from(count);
+            (count, mean, m2 + delta * (x - mean))
+        })
+        .map(|(count, _, m2)| (count, m2))
+    }
+
+    /// Folds the values in chunks. Returns `None` if a `NaN` is found and the
+    /// mode is `NanMode::Propagate`, and otherwise skips `NaN`.
+    pub(crate) fn fold_values<T>(
+        &self,
+        nan: NanMode,
+        init: T,
+        mut f: impl FnMut(T, f64) -> T,
+    ) -> Option<T> {
+        let mut accumulator = init;
+        let mut chunks = self.iter_chunks(CHUNK_LEN);
+        while let Some(chunk) = chunks.next_chunk() {
+            for &x in chunk {
+                if x.is_nan() {
+                    match nan {
+                        NanMode::Propagate => return None,
+                        NanMode::Ignore => continue,
+                    }
+                }
+                accumulator = f(accumulator, x);
+            }
+        }
+        Some(accumulator)
+    }
+}
@@ -0,0 +1,118 @@
+//! Bindings to methods of `%TypedArray%.prototype` that `js_sys` does not
+//! expose on the individual typed array types, and to `ArrayBuffer` features
+//! that are not available in every engine.
+
+use js_sys::{Iterator, JsString, Object, Uint8Array};
+use wasm_bindgen::prelude::*;
+
+#[wasm_bindgen]
+extern "C" {
+    /// A typed array of any kind. Every `TypedArray` variant can be cast to
+    /// this type with `JsCast::unchecked_ref`.
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Clone, Debug)]
+    pub type AnyTypedArray;
+
+    #[wasm_bindgen(method)]
+    pub fn reverse(this: &AnyTypedArray) -> AnyTypedArray;
+
+    #[wasm_bindgen(method)]
+    pub fn sort(this: &AnyTypedArray) -> AnyTypedArray;
+
+    #[wasm_bindgen(method, js_name = sort)]
+    pub fn sort_by(
+        this: &AnyTypedArray,
+        compare_fn: &mut dyn FnMut(f64, f64) -> i32,
+    ) -> AnyTypedArray;
+
+    #[wasm_bindgen(method, js_name = indexOf)]
+    pub fn index_of(this: &AnyTypedArray, search_element: f64, from_index: i32) -> i32;
+
+    #[wasm_bindgen(method, js_name = lastIndexOf)]
+    pub fn last_index_of(this: &AnyTypedArray, search_element: f64, from_index: i32) -> i32;
+
+    #[wasm_bindgen(method)]
+    pub fn includes(this: &AnyTypedArray, search_element: f64, from_index: i32) -> bool;
+
+    #[wasm_bindgen(method)]
+    pub fn find(this: &AnyTypedArray, predicate: &mut dyn FnMut(f64, u32) -> bool) -> JsValue;
+
+    #[wasm_bindgen(method, js_name = findIndex)]
+    pub fn find_index(this: &AnyTypedArray, predicate: &mut dyn FnMut(f64, u32) -> bool) -> i32;
+
+    #[wasm_bindgen(method)]
+    pub fn every(this: &AnyTypedArray, predicate: &mut dyn FnMut(f64, u32) -> bool) -> bool;
+
+    #[wasm_bindgen(method)]
+    pub fn some(this: &AnyTypedArray, predicate: &mut dyn FnMut(f64, u32) -> bool) -> bool;
+
+    #[wasm_bindgen(method)]
+    pub fn join(this: &AnyTypedArray, separator: &str) -> JsString;
+
+    #[wasm_bindgen(method, js_name = toLocaleString)]
+    pub fn to_locale_string(this: &AnyTypedArray, locales: &JsValue, options: &JsValue)
+        -> JsString;
+
+    #[wasm_bindgen(method, js_name = forEach)]
+    pub fn for_each(this: &AnyTypedArray, callback: &mut dyn FnMut(f64, u32));
+
+    #[wasm_bindgen(method)]
+    pub fn entries(this: &AnyTypedArray) -> Iterator;
+
+    #[wasm_bindgen(method)]
+    pub fn keys(this: &AnyTypedArray) -> Iterator;
+
+    #[wasm_bindgen(method)]
+    pub fn values(this: &AnyTypedArray) -> Iterator;
+
+    #[wasm_bindgen(method, js_name = subarray)]
+    pub fn subarray_from(this: &AnyTypedArray, begin: u32) -> AnyTypedArray;
+
+    #[wasm_bindgen(method, js_name = slice)]
+    pub fn slice_from(this: &AnyTypedArray, begin: u32) -> AnyTypedArray;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// An `ArrayBuffer` or `SharedArrayBuffer`, with bindings that return
+    /// `None` instead of a wrong value when the engine does not support them.
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Clone, Debug)]
+    pub type AnyArrayBuffer;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn detached(this: &AnyArrayBuffer) -> Option<bool>;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn resizable(this: &AnyArrayBuffer) -> Option<bool>;
+
+    #[wasm_bindgen(method, getter)]
+    pub fn growable(this: &AnyArrayBuffer) -> Option<bool>;
+
+    #[wasm_bindgen(method, getter, js_name = maxByteLength)]
+    pub fn max_byte_length(this: &AnyArrayBuffer) -> Option<u32>;
+
+    /// Creates a `Uint8Array` over the whole buffer, which fails if it is
+    /// detached.
+    #[wasm_bindgen(constructor, catch, js_class = "Uint8Array")]
+    pub fn try_view(buffer: &AnyArrayBuffer) -> Result<AnyTypedArray, JsValue>;
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// A `TextDecoder`, which is part of the Encoding API rather than
+    /// ECMAScript, so `js_sys` does not bind it.
+    #[wasm_bindgen(extends = Object)]
+    #[derive(Clone, Debug)]
+    pub type TextDecoder;
+
+    #[wasm_bindgen(constructor, catch)]
+    pub fn new(label: &str, options: &JsValue) -> Result<TextDecoder, JsValue>;
+
+    #[wasm_bindgen(method, catch)]
+    pub fn decode(
+        this: &TextDecoder,
+        input: &Uint8Array,
+        options: &JsValue,
+    ) -> Result<String, JsValue>;
+}
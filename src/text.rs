@@ -0,0 +1,216 @@
+//! Encoding the bytes of typed arrays as text.
+
+use crate::wire::{self, DecodeError};
+use crate::{TypedArray, TypedArrayKind};
+
+/// The alphabet used by [`to_base64`] and [`from_base64`].
+///
+/// [`to_base64`]: enum.TypedArray.html#method.to_base64
+/// [`from_base64`]: enum.TypedArray.html#method.from_base64
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug)]
+pub enum Base64Alphabet {
+    /// The standard alphabet of RFC 4648, with `+` and `/`, padded with `=`.
+    Standard,
+    /// The URL and filename safe alphabet of RFC 4648, with `-` and `_`,
+    /// without padding.
+    UrlSafe,
+}
+
+impl Base64Alphabet {
+    fn characters(self) -> &'static [u8; 64] {
+        match self {
+            Base64Alphabet::Standard => {
+                b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/"
+            }
+            Base64Alphabet::UrlSafe => {
+                b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_"
+            }
+        }
+    }
+
+    fn value(self, character: u8) -> Option<u8> {
+        self.characters()
+            .iter()
+            .position(|&c| c == character)
+            .map(|value| value as u8)
+    }
+}
+
+impl TypedArray {
+    /// Encodes the bytes of this typed array as base64, using the given
+    /// alphabet.
+    pub fn to_base64(&self, alphabet: Base64Alphabet) -> String {
+        let characters = alphabet.characters();
+        let bytes = self.as_bytes().to_vec();
+        let mut base64 = String::with_capacity(bytes.len().div_ceil(3) * 4);
+        for group in bytes.chunks(3) {
+            let bits = group
+                .iter()
+                .enumerate()
+                .fold(0, |bits, (i, &byte)| bits | u32::from(byte) << (16 - 8 * i));
+            for i in 0..=group.len() {
+                base64.push(characters[(bits >> (18 - 6 * i) & 0x3f) as usize] as char);
+            }
+            if alphabet == Base64Alphabet::Standard {
+                for _ in group.len()..3 {
+                    base64.push('=');
+                }
+            }
+        }
+        base64
+    }
+
+    /// Decodes base64 in the given alphabet into a new typed array of the
+    /// given kind. Padding is optional for both alphabets, but whitespace and
+    /// other characters are not allowed. Returns an error if the number of
+    /// bytes is not a multiple of the element size.
+    ///
+    /// Returns an error if the typed array would exceed the limit set with
+    /// [`set_allocation_limit`].
+    ///
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    pub fn from_base64(
+        kind: TypedArrayKind,
+        base64: &str,
+        alphabet: Base64Alphabet,
+    ) -> Result<Self, DecodeError> {
+        let encoded = base64.as_bytes();
+        let unpadded = match encoded.len() % 4 {
+            0 if encoded.ends_with(b"==") => encoded.len() - 2,
+            0 if encoded.ends_with(b"=") => encoded.len() - 1,
+            _ => encoded.len(),
+        };
+        if unpadded % 4 == 1 {
+            return Err(DecodeError::new(unpadded - 1));
+        }
+        let mut bytes = Vec::with_capacity(unpadded / 4 * 3 + 2);
+        for (group_index, group) in encoded[..unpadded].chunks(4).enumerate() {
+            let mut bits = 0;
+            for (i, &character) in group.iter().enumerate() {
+                let value = alphabet
+                    .value(character)
+                    .ok_or_else(|| DecodeError::new(4 * group_index + i))?;
+                bits |= u32::from(value) << (18 - 6 * i);
+            }
+            // The bits after the last byte must be zero.
+            let byte_count = group.len() - 1;
+            if bits & (0xff_ffff >> (8 * byte_count)) != 0 {
+                return Err(DecodeError::new(4 * group_index + group.len() - 1));
+            }
+            for i in 0..byte_count {
+                bytes.push((bits >> (16 - 8 * i)) as u8);
+            }
+        }
+        wire::from_element_bytes(kind, &bytes, false, base64.len())
+    }
+
+    /// Encodes the bytes of this typed array as lowercase hexadecimal, two
+    /// digits per byte, like `"00ff7a"`. See [`to_hex_upper`] for uppercase.
+    ///
+    /// [`to_hex_upper`]: #method.to_hex_upper
+    pub fn to_hex(&self) -> String {
+        self.hex(b"0123456789abcdef")
+    }
+
+    /// Encodes the bytes of this typed array as uppercase hexadecimal, two
+    /// digits per byte, like `"00FF7A"`.
+    pub fn to_hex_upper(&self) -> String {
+        self.hex(b"0123456789ABCDEF")
+    }
+
+    fn hex(&self, digits: &[u8; 16]) -> String {
+        let bytes = self.as_bytes().to_vec();
+        let mut hex = String::with_capacity(2 * bytes.len());
+        for byte in bytes {
+            hex.push(digits[usize::from(byte >> 4)] as char);
+            hex.push(digits[usize::from(byte & 0xf)] as char);
+        }
+        hex
+    }
+
+    /// Decodes hexadecimal with two digits per byte, in either case, into a
+    /// new typed array of the given kind. Returns an error if the input
+    /// contains anything other than digits, has an odd length, or if the
+    /// number of bytes is not a multiple of the element size.
+    ///
+    /// Returns an error if the typed array would exceed the limit set with
+    /// [`set_allocation_limit`].
+    ///
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    pub fn from_hex(kind: TypedArrayKind, hex: &str) -> Result<Self, DecodeError> {
+        let digits = hex.as_bytes();
+        if digits.len() % 2 != 0 {
+            return Err(DecodeError::new(digits.len()));
+        }
+        let value = |position: usize| {
+            (digits[position] as char)
+                .to_digit(16)
+                .ok_or_else(|| DecodeError::new(position))
+        };
+        let bytes = (0..digits.len())
+            .step_by(2)
+            .map(|position| Ok((value(position)? << 4 | value(position + 1)?) as u8))
+            .collect::<Result<Vec<u8>, DecodeError>>()?;
+        wire::from_element_bytes(kind, &bytes, false, hex.len())
+    }
+
+    /// Encodes the bytes of this typed array as a `data:` URL with the given
+    /// media type, like `data:image/png;base64,iVBORw0KGgo`, which can be used
+    /// as the source of an image or audio element.
+    pub fn to_data_url(&self, mime: &str) -> String {
+        format!(
+            "data:{};base64,{}",
+            mime,
+            self.to_base64(Base64Alphabet::Standard)
+        )
+    }
+
+    /// Decodes the data of a `data:` URL into a new `Uint8Array`. Both base64
+    /// and percent-encoded data are supported. The media type is ignored.
+    ///
+    /// Returns an error if the typed array would exceed the limit set with
+    /// [`set_allocation_limit`].
+    ///
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    pub fn from_data_url(url: &str) -> Result<Self, DecodeError> {
+        const SCHEME: &str = "data:";
+        if !url
+            .get(..SCHEME.len())
+            .is_some_and(|scheme| scheme.eq_ignore_ascii_case(SCHEME))
+        {
+            return Err(DecodeError::new(0));
+        }
+        let comma = url.find(',').ok_or_else(|| DecodeError::new(url.len()))?;
+        let data = &url[comma + 1..];
+        let offset = |error: DecodeError| error.offset_by(comma + 1);
+        let kind = TypedArrayKind::Uint8Array;
+        if url[..comma].to_ascii_lowercase().ends_with(";base64") {
+            TypedArray::from_base64(kind, data, Base64Alphabet::Standard).map_err(offset)
+        } else {
+            let bytes = percent_decode(data.as_bytes()).map_err(offset)?;
+            wire::from_element_bytes(kind, &bytes, false, 0).map_err(offset)
+        }
+    }
+}
+
+/// Decodes `%XX` escapes, leaving all other bytes as they are.
+fn percent_decode(encoded: &[u8]) -> Result<Vec<u8>, DecodeError> {
+    let mut bytes = Vec::with_capacity(encoded.len());
+    let mut position = 0;
+    while position < encoded.len() {
+        if encoded[position] == b'%' {
+            let byte = encoded
+                .get(position + 1..position + 3)
+                .filter(|digits| digits.iter().all(u8::is_ascii_hexdigit))
+                .and_then(|digits| core::str::from_utf8(digits).ok())
+                .and_then(|digits| u8::from_str_radix(digits, 16).ok())
+                .ok_or_else(|| DecodeError::new(position))?;
+            bytes.push(byte);
+            position += 3;
+        } else {
+            bytes.push(encoded[position]);
+            position += 1;
+        }
+    }
+    Ok(bytes)
+}
@@ -0,0 +1,101 @@
+//! Transforming the values of typed arrays in place.
+
+use crate::stats::NanMode;
+use crate::{chunk_ranges, Element, TypedArray, CHUNK_LEN};
+
+impl TypedArray {
+    /// Replaces every value `x` with `a * x + b`, for example to apply a gain
+    /// to audio samples or to convert the units of sensor data. The elements
+    /// are copied into WebAssembly memory in chunks, transformed, and copied
+    /// back.
+    ///
+    /// Results are converted to the element type with an `as` cast, so for
+    /// integer kinds they are truncated and saturate at the limits of the
+    /// type, and `NaN` becomes zero. For a `Uint8ClampedArray` they are
+    /// rounded to the nearest integer instead, with ties to even, the same as
+    /// Javascript does when storing them.
+    pub fn scale_offset(&self, a: f64, b: f64) {
+        self.apply(|x| a * x + b);
+    }
+
+    /// Restricts every value to the range from `min` to `max`, for example
+    /// before converting floating point pixels to a `Uint8Array`. `NaN`
+    /// elements are left unchanged. For integer kinds, the bounds are
+    /// effectively converted to the element type the same way as the results
+    /// of [`scale_offset`].
+    ///
+    /// # Panics
+    /// Panics if `min` is greater than `max`, or either of them is `NaN`.
+    ///
+    /// [`scale_offset`]: #method.scale_offset
+    pub fn clamp_in_place(&self, min: f64, max: f64) {
+        assert!(min <= max, "invalid clamp range");
+        self.apply(|x| x.clamp(min, max));
+    }
+
+    /// Rescales the values linearly in place, so that the smallest becomes
+    /// `target.0` and the largest becomes `target.1`. If all values are the
+    /// same, they all become `target.0`. `NaN` is ignored when finding the
+    /// smallest and largest value, and stays `NaN`. See [`normalize_from`]
+    /// to rescale from a known range instead.
+    ///
+    /// # Panics
+    /// Panics if `target` is not finite, or if a value is infinite.
+    ///
+    /// [`normalize_from`]: #method.normalize_from
+    pub fn normalize(&self, target: (f64, f64)) {
+        if let (Some(min), Some(max)) = (self.min(NanMode::Ignore), self.max(NanMode::Ignore)) {
+            self.normalize_from((min, max), target);
+        }
+    }
+
+    /// Rescales the values linearly in place, so that `source.0` becomes
+    /// `target.0` and `source.1` becomes `target.1`. Values outside of the
+    /// source range end up outside of the target range. If the source range
+    /// is empty, all values become `target.0`.
+    ///
+    /// Results are converted to the element type the same way as those of
+    /// [`scale_offset`].
+    ///
+    /// # Panics
+    /// Panics if `source` or `target` is not finite.
+    ///
+    /// [`scale_offset`]: #method.scale_offset
+    pub fn normalize_from(&self, source: (f64, f64), target: (f64, f64)) {
+        assert!(
+            [source.0, source.1, target.0, target.1]
+                .iter()
+                .all(|bound| bound.is_finite()),
+            "normalization ranges must be finite"
+        );
+        let scale = if source.0 == source.1 {
+            0.0
+        } else {
+            (target.1 - target.0) / (source.1 - source.0)
+        };
+        self.apply(|x| (x - source.0) * scale + target.0);
+    }
+
+    /// Replaces every value with the result of `f`, which is called in order
+    /// from the first element to the last. The elements are copied into
+    /// WebAssembly memory in chunks, transformed, and copied back, which avoids
+    /// a call into Javascript for every element.
+    ///
+    /// Results are converted to the element type the same way as those of
+    /// [`scale_offset`].
+    ///
+    /// [`scale_offset`]: #method.scale_offset
+    pub fn apply(&self, mut f: impl FnMut(f64) -> f64) {
+        let length = self.length();
+        match_every!(self, array, {
+            for (begin, end) in chunk_ranges(length, CHUNK_LEN) {
+                let chunk = array.subarray(begin, end);
+                let mut values = chunk.to_vec();
+                for value in &mut values {
+                    *value = Element::from_f64(self.round_for_storage(f(value.to_f64())));
+                }
+                chunk.copy_from(&values);
+            }
+        })
+    }
+}
@@ -0,0 +1,125 @@
+//! Reading and writing variable-length integers in the LEB128 encoding.
+
+use crate::TypedArray;
+use err_derive::*;
+
+/// The maximum number of bytes of an encoded `u64`.
+pub(crate) const MAX_VARINT_LEN: u32 = 10;
+
+/// Returned when the bytes at a position are not a valid varint, because it
+/// is cut off by the end of the typed array or does not fit in 64 bits.
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error(display = "invalid or truncated varint at byte {}", position)]
+pub struct VarintError {
+    position: u32,
+}
+
+impl VarintError {
+    pub(crate) fn new(position: u32) -> Self {
+        VarintError { position }
+    }
+
+    /// The offset in bytes of the start of the varint.
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+}
+
+impl TypedArray {
+    /// Reads an unsigned LEB128 varint, as used by Protocol Buffers and
+    /// WebAssembly, at `byte_offset` bytes from the start of this typed array.
+    /// Returns the value and the number of bytes it takes up.
+    pub fn read_varint_at(&self, byte_offset: u32) -> Result<(u64, u32), VarintError> {
+        let bytes = self.as_bytes();
+        let end = byte_offset
+            .saturating_add(MAX_VARINT_LEN)
+            .min(bytes.length());
+        let bytes = bytes.subarray(byte_offset.min(end), end).to_vec();
+        decode_varint(&bytes).ok_or(VarintError::new(byte_offset))
+    }
+
+    /// Reads a signed varint in the zigzag encoding of Protocol Buffers, at
+    /// `byte_offset` bytes from the start of this typed array. Returns the
+    /// value and the number of bytes it takes up.
+    pub fn read_zigzag_varint_at(&self, byte_offset: u32) -> Result<(i64, u32), VarintError> {
+        self.read_varint_at(byte_offset)
+            .map(|(value, len)| (zigzag_decode(value), len))
+    }
+
+    /// Writes `value` as an unsigned LEB128 varint at `byte_offset` bytes from
+    /// the start of this typed array, and returns the number of bytes written.
+    ///
+    /// # Panics
+    /// Panics if the encoded value is not within the bytes of this typed
+    /// array.
+    pub fn write_varint_at(&self, byte_offset: u32, value: u64) -> u32 {
+        let (encoded, len) = encode_varint(value);
+        let bytes = self.as_bytes();
+        assert!(
+            byte_offset
+                .checked_add(len)
+                .is_some_and(|end| end <= bytes.length()),
+            "varint out of bounds"
+        );
+        bytes
+            .subarray(byte_offset, byte_offset + len)
+            .copy_from(&encoded[..len as usize]);
+        len
+    }
+
+    /// Writes `value` as a signed varint in the zigzag encoding of Protocol
+    /// Buffers at `byte_offset` bytes from the start of this typed array, and
+    /// returns the number of bytes written.
+    ///
+    /// # Panics
+    /// Panics if the encoded value is not within the bytes of this typed
+    /// array.
+    pub fn write_zigzag_varint_at(&self, byte_offset: u32, value: i64) -> u32 {
+        self.write_varint_at(byte_offset, zigzag_encode(value))
+    }
+}
+
+/// Decodes the varint at the start of `bytes`, returning the value and the
+/// number of bytes it takes up.
+pub(crate) fn decode_varint(bytes: &[u8]) -> Option<(u64, u32)> {
+    let mut value = 0;
+    for (index, &byte) in bytes.iter().take(MAX_VARINT_LEN as usize).enumerate() {
+        // The tenth byte holds only the highest bit of a `u64`.
+        if index == 9 && byte > 1 {
+            return None;
+        }
+        value |= u64::from(byte & 0x7f) << (7 * index);
+        if byte & 0x80 == 0 {
+            return Some((value, index as u32 + 1));
+        }
+    }
+    None
+}
+
+/// Encodes `value` as a varint, returning the bytes and the number of them
+/// that are used.
+pub(crate) fn encode_varint(mut value: u64) -> ([u8; MAX_VARINT_LEN as usize], u32) {
+    let mut encoded = [0; MAX_VARINT_LEN as usize];
+    let mut len = 0;
+    loop {
+        let byte = (value & 0x7f) as u8;
+        value >>= 7;
+        if value == 0 {
+            encoded[len] = byte;
+            return (encoded, len as u32 + 1);
+        }
+        encoded[len] = byte | 0x80;
+        len += 1;
+    }
+}
+
+/// Maps signed integers to unsigned ones, so that values close to zero have a
+/// short encoding.
+pub(crate) fn zigzag_encode(value: i64) -> u64 {
+    ((value << 1) ^ (value >> 63)) as u64
+}
+
+/// The inverse of `zigzag_encode`.
+pub(crate) fn zigzag_decode(value: u64) -> i64 {
+    (value >> 1) as i64 ^ -((value & 1) as i64)
+}
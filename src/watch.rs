@@ -0,0 +1,68 @@
+//! Observing writes to a typed array through a Javascript `Proxy`.
+
+use crate::TypedArray;
+use js_sys::{Function, Object, Proxy, Reflect};
+use wasm_bindgen::prelude::*;
+use wasm_bindgen::JsCast;
+
+impl TypedArray {
+    /// Wraps this typed array in a Javascript `Proxy` that calls `on_write`
+    /// with the index and the new value of every element that is assigned
+    /// through it, like `array[3] = 1.5` in Javascript. The value is read back
+    /// after the write, so it has already been converted to the element type.
+    ///
+    /// The returned typed array is a different Javascript object that views
+    /// the same memory, and can be passed to Javascript code in place of the
+    /// original to find out which code writes to it. Writes through the
+    /// original handle, and writes by methods such as [`fill`] and [`set`],
+    /// are not reported.
+    ///
+    /// This method requires the `watch` feature.
+    ///
+    /// [`fill`]: #method.fill
+    /// [`set`]: #method.set
+    pub fn watched(self, on_write: impl FnMut(u32, f64) + 'static) -> TypedArray {
+        let mut on_write = on_write;
+        let target: &JsValue = self.as_ref();
+        let handler = Object::new();
+        let set = Closure::<dyn FnMut(JsValue, JsValue, JsValue) -> Result<bool, JsValue>>::new(
+            move |target: JsValue, property: JsValue, value: JsValue| {
+                let written = Reflect::set(&target, &property, &value)?;
+                if let Some(index) = element_index(&target, &property) {
+                    let value = Reflect::get(&target, &property)?;
+                    on_write(index, value.as_f64().unwrap_or(f64::NAN));
+                }
+                Ok(written)
+            },
+        );
+        // Methods and getters must be called on the typed array itself, since
+        // the proxy lacks its internal slots.
+        let get = Closure::<dyn FnMut(JsValue, JsValue) -> Result<JsValue, JsValue>>::new(
+            |target: JsValue, property: JsValue| {
+                let value = Reflect::get(&target, &property)?;
+                match value.dyn_ref::<Function>() {
+                    Some(function) => Ok(function.bind0(&target).into()),
+                    None => Ok(value),
+                }
+            },
+        );
+        Reflect::set(&handler, &"set".into(), &set.into_js_value())
+            .expect("setting a property of a new object cannot fail");
+        Reflect::set(&handler, &"get".into(), &get.into_js_value())
+            .expect("setting a property of a new object cannot fail");
+        let proxy = Proxy::new(target, &handler);
+        self.same_kind(proxy.unchecked_into())
+    }
+}
+
+/// The index of the element of `target` that `property` refers to, if any.
+fn element_index(target: &JsValue, property: &JsValue) -> Option<u32> {
+    let property = property.as_string()?;
+    let index: u32 = property.parse().ok()?;
+    let length = Reflect::get(target, &"length".into()).ok()?.as_f64()?;
+    if index.to_string() == property && f64::from(index) < length {
+        Some(index)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,225 @@
+//! Wrapping PCM samples in the WAV container format.
+
+use crate::wire::{self, DecodeError};
+use crate::{TypedArray, TypedArrayKind};
+use core::convert::TryFrom;
+use err_derive::*;
+
+/// The format tag for integer samples.
+const FORMAT_PCM: u16 = 1;
+
+/// The format tag for floating point samples.
+const FORMAT_IEEE_FLOAT: u16 = 3;
+
+/// The format tag that moves the actual format tag into the extension.
+const FORMAT_EXTENSIBLE: u16 = 0xfffe;
+
+/// Returned by [`to_wav_bytes`] for a typed array whose kind has no sample
+/// format in WAV files. Only `Uint8Array`, `Int16Array`, `Int32Array`,
+/// `Float32Array` and `Float64Array` do.
+///
+/// [`to_wav_bytes`]: enum.TypedArray.html#method.to_wav_bytes
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error(
+    display = "a typed array of kind {:?} cannot be stored in a WAV file",
+    kind
+)]
+pub struct WavKindError {
+    kind: TypedArrayKind,
+}
+
+impl WavKindError {
+    /// The kind of the typed array.
+    pub fn kind(&self) -> TypedArrayKind {
+        self.kind
+    }
+}
+
+/// The samples and format of a WAV file, returned by [`from_wav_bytes`].
+///
+/// [`from_wav_bytes`]: enum.TypedArray.html#method.from_wav_bytes
+#[derive(Clone, Debug)]
+pub struct WavAudio {
+    samples: TypedArray,
+    sample_rate: u32,
+    channels: u16,
+}
+
+impl WavAudio {
+    /// The samples, with the samples of the channels interleaved.
+    pub fn samples(&self) -> &TypedArray {
+        &self.samples
+    }
+
+    /// Returns the samples, with the samples of the channels interleaved.
+    pub fn into_samples(self) -> TypedArray {
+        self.samples
+    }
+
+    /// The number of samples per second of each channel.
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    /// The number of channels.
+    pub fn channels(&self) -> u16 {
+        self.channels
+    }
+}
+
+impl TypedArray {
+    /// Wraps the samples in this typed array in a WAV file, with the samples
+    /// of the `channels` channels interleaved. An `Int16Array` or `Int32Array`
+    /// is written as PCM, a `Uint8Array` as 8-bit PCM, which is unsigned, and
+    /// a `Float32Array` or `Float64Array` as IEEE floats. Other kinds return a
+    /// [`WavKindError`].
+    ///
+    /// # Panics
+    /// Panics if `channels` is zero or the length is not a multiple of it, or
+    /// if the file would be larger than 4 GiB.
+    ///
+    /// [`WavKindError`]: struct.WavKindError.html
+    pub fn to_wav_bytes(&self, sample_rate: u32, channels: u16) -> Result<Vec<u8>, WavKindError> {
+        let format = match self.kind() {
+            TypedArrayKind::Uint8Array
+            | TypedArrayKind::Int16Array
+            | TypedArrayKind::Int32Array => FORMAT_PCM,
+            TypedArrayKind::Float32Array | TypedArrayKind::Float64Array => FORMAT_IEEE_FLOAT,
+            kind => return Err(WavKindError { kind }),
+        };
+        assert!(
+            channels != 0 && self.length() % u32::from(channels) == 0,
+            "the length is not a multiple of the number of channels"
+        );
+        let bytes_per_sample = self.kind().bytes_per_element() as u16;
+        let block_align = bytes_per_sample * channels;
+        let data_len = self.byte_length();
+        // Formats other than PCM have an extension size in the format chunk and
+        // need a fact chunk.
+        let (format_len, fact_len) = if format == FORMAT_PCM {
+            (16u32, 0)
+        } else {
+            (18, 12)
+        };
+        let padding = data_len % 2;
+        let riff_len = (4 + 8 + format_len + fact_len + 8)
+            .checked_add(data_len + padding)
+            .expect("the WAV file would be larger than 4 GiB");
+        let mut wav = Vec::with_capacity(8 + riff_len as usize);
+        wav.extend_from_slice(b"RIFF");
+        wav.extend_from_slice(&riff_len.to_le_bytes());
+        wav.extend_from_slice(b"WAVE");
+        wav.extend_from_slice(b"fmt ");
+        wav.extend_from_slice(&format_len.to_le_bytes());
+        wav.extend_from_slice(&format.to_le_bytes());
+        wav.extend_from_slice(&channels.to_le_bytes());
+        wav.extend_from_slice(&sample_rate.to_le_bytes());
+        let byte_rate = u64::from(sample_rate) * u64::from(block_align);
+        wav.extend_from_slice(&u32::try_from(byte_rate).unwrap_or(u32::MAX).to_le_bytes());
+        wav.extend_from_slice(&block_align.to_le_bytes());
+        wav.extend_from_slice(&(bytes_per_sample * 8).to_le_bytes());
+        if format != FORMAT_PCM {
+            wav.extend_from_slice(&0u16.to_le_bytes());
+            wav.extend_from_slice(b"fact");
+            wav.extend_from_slice(&4u32.to_le_bytes());
+            let frames = self.length() / u32::from(channels);
+            wav.extend_from_slice(&frames.to_le_bytes());
+        }
+        wav.extend_from_slice(b"data");
+        wav.extend_from_slice(&data_len.to_le_bytes());
+        wav.extend_from_slice(&self.as_bytes().to_vec());
+        if padding != 0 {
+            wav.push(0);
+        }
+        Ok(wav)
+    }
+
+    /// Reads the samples and format of a WAV file written by [`to_wav_bytes`],
+    /// or by another program. The samples must be 8, 16 or 32-bit PCM, or 32
+    /// or 64-bit IEEE floats, which result in a typed array of the same kind
+    /// that `to_wav_bytes` accepts. Chunks other than the format and the data
+    /// are skipped.
+    ///
+    /// Returns an error if the typed array would exceed the limit set with
+    /// [`set_allocation_limit`].
+    ///
+    /// [`to_wav_bytes`]: #method.to_wav_bytes
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    pub fn from_wav_bytes(wav: &[u8]) -> Result<WavAudio, DecodeError> {
+        if wav.get(0..4) != Some(&b"RIFF"[..]) || wav.get(8..12) != Some(&b"WAVE"[..]) {
+            return Err(DecodeError::new(0));
+        }
+        let mut format = None;
+        let mut position = 12;
+        while position < wav.len() {
+            let header = wav
+                .get(position..position + 8)
+                .ok_or(DecodeError::new(position))?;
+            let len = u32_at(header, 4) as usize;
+            let start = position + 8;
+            let body = wav
+                .get(start..start.saturating_add(len))
+                .ok_or(DecodeError::new(position + 4))?;
+            match &header[0..4] {
+                b"fmt " => format = Some(parse_format(body).ok_or(DecodeError::new(start))?),
+                b"data" => {
+                    let (kind, channels, sample_rate) = format.ok_or(DecodeError::new(position))?;
+                    let block_align = kind.bytes_per_element() as usize * usize::from(channels);
+                    if body.len() % block_align != 0 {
+                        return Err(DecodeError::new(position + 4));
+                    }
+                    let samples = wire::from_element_bytes(kind, body, false, position + 4)?;
+                    return Ok(WavAudio {
+                        samples,
+                        sample_rate,
+                        channels,
+                    });
+                }
+                _ => {}
+            }
+            // Chunks are padded to an even length.
+            position = start + len + len % 2;
+        }
+        Err(DecodeError::new(wav.len()))
+    }
+}
+
+/// Reads a little endian `u16` at `offset`.
+fn u16_at(bytes: &[u8], offset: usize) -> u16 {
+    u16::from_le_bytes([bytes[offset], bytes[offset + 1]])
+}
+
+/// Reads a little endian `u32` at `offset`.
+fn u32_at(bytes: &[u8], offset: usize) -> u32 {
+    let mut value = [0; 4];
+    value.copy_from_slice(&bytes[offset..offset + 4]);
+    u32::from_le_bytes(value)
+}
+
+/// Reads the kind of the samples, the number of channels and the sample rate
+/// from the body of a format chunk.
+fn parse_format(body: &[u8]) -> Option<(TypedArrayKind, u16, u32)> {
+    if body.len() < 16 {
+        return None;
+    }
+    let mut format = u16_at(body, 0);
+    if format == FORMAT_EXTENSIBLE {
+        // The first two bytes of the sub format GUID are the format tag.
+        format = u16_at(body.get(..26)?, 24);
+    }
+    let channels = u16_at(body, 2);
+    let sample_rate = u32_at(body, 4);
+    let bits_per_sample = u16_at(body, 14);
+    let kind = match (format, bits_per_sample) {
+        (FORMAT_PCM, 8) => TypedArrayKind::Uint8Array,
+        (FORMAT_PCM, 16) => TypedArrayKind::Int16Array,
+        (FORMAT_PCM, 32) => TypedArrayKind::Int32Array,
+        (FORMAT_IEEE_FLOAT, 32) => TypedArrayKind::Float32Array,
+        (FORMAT_IEEE_FLOAT, 64) => TypedArrayKind::Float64Array,
+        _ => return None,
+    };
+    if channels == 0 {
+        return None;
+    }
+    Some((kind, channels, sample_rate))
+}
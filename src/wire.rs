@@ -0,0 +1,227 @@
+//! Encoding typed arrays in binary formats that preserve their kind.
+
+use crate::{AllocationLimitError, TypedArray, TypedArrayKind};
+use core::convert::TryFrom;
+use err_derive::*;
+
+/// The first bytes of the format written by `encode_binary`.
+const MAGIC: &[u8; 4] = b"TYAR";
+
+/// The version of the format written by `encode_binary`.
+const VERSION: u8 = 1;
+
+/// The size of the header written by `encode_binary`.
+const HEADER_LEN: usize = 16;
+
+/// Returned when bytes cannot be decoded as an encoded typed array, because
+/// they are malformed, truncated, use an unsupported element type, or describe
+/// a typed array that would exceed the limit set with
+/// [`set_allocation_limit`].
+///
+/// [`set_allocation_limit`]: fn.set_allocation_limit.html
+#[derive(Copy, Clone, Eq, PartialEq, Hash, Debug, Error)]
+#[error(
+    display = "invalid or unsupported encoded typed array at byte {}",
+    position
+)]
+pub struct DecodeError {
+    position: usize,
+    allocation_limit_error: Option<AllocationLimitError>,
+}
+
+impl DecodeError {
+    pub(crate) fn new(position: usize) -> Self {
+        DecodeError {
+            position,
+            allocation_limit_error: None,
+        }
+    }
+
+    /// The same error, found `offset` bytes later in a larger input.
+    pub(crate) fn offset_by(self, offset: usize) -> Self {
+        DecodeError {
+            position: offset + self.position,
+            ..self
+        }
+    }
+
+    /// The offset in bytes in the input at which the error was found.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// The reason the typed array was not created, if the input is valid but
+    /// the typed array would exceed the allocation limit.
+    pub fn allocation_limit_error(&self) -> Option<AllocationLimitError> {
+        self.allocation_limit_error
+    }
+}
+
+impl TypedArray {
+    /// Encodes this typed array in a simple binary format, which consists of a
+    /// header of 16 bytes followed by the bytes of the elements:
+    ///
+    /// | Bytes | Contents                                              |
+    /// |-------|-------------------------------------------------------|
+    /// | 0..4  | The magic bytes `TYAR`                                |
+    /// | 4     | The version of the format, currently 1                |
+    /// | 5     | The kind                                              |
+    /// | 6     | The byte order: 0 for little endian, 1 for big endian |
+    /// | 7     | Reserved, always 0                                    |
+    /// | 8..16 | The number of elements, as a little endian `u64`      |
+    ///
+    /// The kind is 0 for `Int8Array`, 1 for `Uint8Array`, 2 for
+    /// `Uint8ClampedArray`, 3 for `Int16Array`, 4 for `Uint16Array`, 5 for
+    /// `Int32Array`, 6 for `Uint32Array`, 7 for `Float32Array` and 8 for
+    /// `Float64Array`. The elements are written in little endian byte order,
+    /// and start at an offset that is aligned for every element type.
+    pub fn encode_binary(&self) -> Vec<u8> {
+        let mut encoded = Vec::with_capacity(HEADER_LEN + self.byte_length() as usize);
+        encoded.extend_from_slice(MAGIC);
+        encoded.push(VERSION);
+        encoded.push(kind_code(self.kind()));
+        encoded.push(0);
+        encoded.push(0);
+        encoded.extend_from_slice(&u64::from(self.length()).to_le_bytes());
+        encoded.extend_from_slice(&self.as_bytes().to_vec());
+        encoded
+    }
+
+    /// Decodes a typed array encoded by [`encode_binary`], or by another
+    /// implementation of the format, which may use big endian elements.
+    /// Returns an error if the typed array would exceed the limit set with
+    /// [`set_allocation_limit`].
+    ///
+    /// [`encode_binary`]: #method.encode_binary
+    /// [`set_allocation_limit`]: fn.set_allocation_limit.html
+    pub fn decode_binary(encoded: &[u8]) -> Result<Self, DecodeError> {
+        let header = encoded
+            .get(..HEADER_LEN)
+            .ok_or(DecodeError::new(encoded.len()))?;
+        if &header[0..4] != MAGIC {
+            return Err(DecodeError::new(0));
+        }
+        if header[4] != VERSION {
+            return Err(DecodeError::new(4));
+        }
+        let kind = kind_of_code(header[5]).ok_or(DecodeError::new(5))?;
+        let big_endian = match header[6] {
+            0 => false,
+            1 => true,
+            _ => return Err(DecodeError::new(6)),
+        };
+        if header[7] != 0 {
+            return Err(DecodeError::new(7));
+        }
+        let mut length = [0; 8];
+        length.copy_from_slice(&header[8..16]);
+        let byte_length = u64::from_le_bytes(length)
+            .checked_mul(u64::from(kind.bytes_per_element()))
+            .ok_or(DecodeError::new(8))?;
+        let bytes = &encoded[HEADER_LEN..];
+        if bytes.len() as u64 != byte_length {
+            return Err(DecodeError::new(8));
+        }
+        from_element_bytes(kind, bytes, big_endian, 8)
+    }
+}
+
+/// The code of a kind in the format of `encode_binary`.
+fn kind_code(kind: TypedArrayKind) -> u8 {
+    match kind {
+        TypedArrayKind::Int8Array => 0,
+        TypedArrayKind::Uint8Array => 1,
+        TypedArrayKind::Uint8ClampedArray => 2,
+        TypedArrayKind::Int16Array => 3,
+        TypedArrayKind::Uint16Array => 4,
+        TypedArrayKind::Int32Array => 5,
+        TypedArrayKind::Uint32Array => 6,
+        TypedArrayKind::Float32Array => 7,
+        TypedArrayKind::Float64Array => 8,
+    }
+}
+
+/// The kind with the given code in the format of `encode_binary`.
+fn kind_of_code(code: u8) -> Option<TypedArrayKind> {
+    Some(match code {
+        0 => TypedArrayKind::Int8Array,
+        1 => TypedArrayKind::Uint8Array,
+        2 => TypedArrayKind::Uint8ClampedArray,
+        3 => TypedArrayKind::Int16Array,
+        4 => TypedArrayKind::Uint16Array,
+        5 => TypedArrayKind::Int32Array,
+        6 => TypedArrayKind::Uint32Array,
+        7 => TypedArrayKind::Float32Array,
+        8 => TypedArrayKind::Float64Array,
+        _ => return None,
+    })
+}
+
+/// Creates a typed array of the given kind from the bytes of its elements,
+/// swapping the byte order if they are big endian. Returns an error at
+/// `position` if the number of bytes is not a multiple of the element size, or
+/// if the typed array would exceed the allocation limit.
+pub(crate) fn from_element_bytes(
+    kind: TypedArrayKind,
+    bytes: &[u8],
+    big_endian: bool,
+    position: usize,
+) -> Result<TypedArray, DecodeError> {
+    let bytes_per_element = kind.bytes_per_element() as usize;
+    if bytes.len() % bytes_per_element != 0 {
+        return Err(DecodeError::new(position));
+    }
+    let length =
+        u32::try_from(bytes.len() / bytes_per_element).map_err(|_| DecodeError::new(position))?;
+    let array = TypedArray::try_new_with_length(kind, length).map_err(|error| DecodeError {
+        position,
+        allocation_limit_error: Some(error),
+    })?;
+    array.as_bytes().copy_from(bytes);
+    if big_endian {
+        array.swap_bytes();
+    }
+    Ok(array)
+}
+
+/// The CBOR tag of RFC 8746 for a typed array of the given kind with little
+/// endian elements.
+#[cfg(any(feature = "cbor", feature = "msgpack"))]
+pub(crate) fn cbor_tag(kind: TypedArrayKind) -> u8 {
+    match kind {
+        TypedArrayKind::Uint8Array => 64,
+        TypedArrayKind::Uint8ClampedArray => 68,
+        TypedArrayKind::Uint16Array => 69,
+        TypedArrayKind::Uint32Array => 70,
+        TypedArrayKind::Int8Array => 72,
+        TypedArrayKind::Int16Array => 77,
+        TypedArrayKind::Int32Array => 78,
+        TypedArrayKind::Float32Array => 85,
+        TypedArrayKind::Float64Array => 86,
+    }
+}
+
+/// The kind, and whether the elements are big endian, for a CBOR tag of RFC
+/// 8746. Returns `None` for tags of element types that have no kind, such as
+/// 64-bit integers and half precision floats.
+#[cfg(any(feature = "cbor", feature = "msgpack"))]
+pub(crate) fn kind_of_cbor_tag(tag: u64) -> Option<(TypedArrayKind, bool)> {
+    Some(match tag {
+        64 => (TypedArrayKind::Uint8Array, false),
+        68 => (TypedArrayKind::Uint8ClampedArray, false),
+        65 => (TypedArrayKind::Uint16Array, true),
+        69 => (TypedArrayKind::Uint16Array, false),
+        66 => (TypedArrayKind::Uint32Array, true),
+        70 => (TypedArrayKind::Uint32Array, false),
+        72 => (TypedArrayKind::Int8Array, false),
+        73 => (TypedArrayKind::Int16Array, true),
+        77 => (TypedArrayKind::Int16Array, false),
+        74 => (TypedArrayKind::Int32Array, true),
+        78 => (TypedArrayKind::Int32Array, false),
+        81 => (TypedArrayKind::Float32Array, true),
+        85 => (TypedArrayKind::Float32Array, false),
+        82 => (TypedArrayKind::Float64Array, true),
+        86 => (TypedArrayKind::Float64Array, false),
+        _ => return None,
+    })
+}
@@ -1,4 +1,7 @@
-use typed_array::{TryFromJsValueError, TryFromTypedArrayError};
+use typed_array::{
+    AlignmentError, AllocationLimitError, ArenaFullError, AtomicKindError, ByteRangeError, ConcatError, DecodeError, EndOfBitsError, EndOfBufferError, GatherError, IndexError, JsonError,
+    KindMismatchError, NonFiniteError, NotSharedMemoryError, RangeError, ScatterError, TryFromJsValueError, TryFromTypedArrayError, Utf16Error, VarintError, WavKindError,
+};
 use wasm_bindgen_test::*;
 
 #[wasm_bindgen_test]
@@ -24,3 +27,243 @@ fn test_try_from_typed_array_error_sync() {
     fn assert_sync<T: Sync>() {}
     assert_sync::<TryFromTypedArrayError>();
 }
+
+#[wasm_bindgen_test]
+fn test_range_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<RangeError>();
+}
+
+#[wasm_bindgen_test]
+fn test_range_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<RangeError>();
+}
+
+#[wasm_bindgen_test]
+fn test_kind_mismatch_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<KindMismatchError>();
+}
+
+#[wasm_bindgen_test]
+fn test_kind_mismatch_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<KindMismatchError>();
+}
+
+#[wasm_bindgen_test]
+fn test_byte_range_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<ByteRangeError>();
+}
+
+#[wasm_bindgen_test]
+fn test_byte_range_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<ByteRangeError>();
+}
+
+#[wasm_bindgen_test]
+fn test_end_of_buffer_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<EndOfBufferError>();
+}
+
+#[wasm_bindgen_test]
+fn test_end_of_buffer_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<EndOfBufferError>();
+}
+
+#[wasm_bindgen_test]
+fn test_alignment_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<AlignmentError>();
+}
+
+#[wasm_bindgen_test]
+fn test_alignment_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<AlignmentError>();
+}
+
+#[wasm_bindgen_test]
+fn test_atomic_kind_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<AtomicKindError>();
+}
+
+#[wasm_bindgen_test]
+fn test_atomic_kind_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<AtomicKindError>();
+}
+
+#[wasm_bindgen_test]
+fn test_not_shared_memory_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<NotSharedMemoryError>();
+}
+
+#[wasm_bindgen_test]
+fn test_not_shared_memory_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<NotSharedMemoryError>();
+}
+
+#[wasm_bindgen_test]
+fn test_arena_full_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<ArenaFullError>();
+}
+
+#[wasm_bindgen_test]
+fn test_arena_full_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<ArenaFullError>();
+}
+
+#[wasm_bindgen_test]
+fn test_allocation_limit_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<AllocationLimitError>();
+}
+
+#[wasm_bindgen_test]
+fn test_allocation_limit_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<AllocationLimitError>();
+}
+
+#[wasm_bindgen_test]
+fn test_json_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<JsonError>();
+}
+
+#[wasm_bindgen_test]
+fn test_json_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<JsonError>();
+}
+
+#[wasm_bindgen_test]
+fn test_non_finite_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<NonFiniteError>();
+}
+
+#[wasm_bindgen_test]
+fn test_non_finite_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<NonFiniteError>();
+}
+
+#[wasm_bindgen_test]
+fn test_decode_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<DecodeError>();
+}
+
+#[wasm_bindgen_test]
+fn test_decode_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<DecodeError>();
+}
+
+#[wasm_bindgen_test]
+fn test_wav_kind_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<WavKindError>();
+}
+
+#[wasm_bindgen_test]
+fn test_wav_kind_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<WavKindError>();
+}
+
+#[wasm_bindgen_test]
+fn test_varint_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<VarintError>();
+}
+
+#[wasm_bindgen_test]
+fn test_varint_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<VarintError>();
+}
+
+#[wasm_bindgen_test]
+fn test_utf16_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<Utf16Error>();
+}
+
+#[wasm_bindgen_test]
+fn test_utf16_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<Utf16Error>();
+}
+
+#[wasm_bindgen_test]
+fn test_end_of_bits_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<EndOfBitsError>();
+}
+
+#[wasm_bindgen_test]
+fn test_end_of_bits_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<EndOfBitsError>();
+}
+
+#[wasm_bindgen_test]
+fn test_index_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<IndexError>();
+}
+
+#[wasm_bindgen_test]
+fn test_index_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<IndexError>();
+}
+
+#[wasm_bindgen_test]
+fn test_scatter_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<ScatterError>();
+}
+
+#[wasm_bindgen_test]
+fn test_scatter_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<ScatterError>();
+}
+
+#[wasm_bindgen_test]
+fn test_concat_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<ConcatError>();
+}
+
+#[wasm_bindgen_test]
+fn test_concat_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<ConcatError>();
+}
+
+#[wasm_bindgen_test]
+fn test_gather_error_send() {
+    fn assert_send<T: Send>() {}
+    assert_send::<GatherError>();
+}
+
+#[wasm_bindgen_test]
+fn test_gather_error_sync() {
+    fn assert_sync<T: Sync>() {}
+    assert_sync::<GatherError>();
+}
@@ -0,0 +1,36 @@
+#![cfg(feature = "serde-wasm-bindgen")]
+
+use js_sys::*;
+use serde::{Deserialize, Serialize};
+use typed_array::{TypedArray, TypedArrayKind};
+use wasm_bindgen::JsValue;
+use wasm_bindgen_test::*;
+
+#[derive(Serialize, Deserialize)]
+struct Message {
+    #[serde(with = "typed_array::preserve")]
+    array: TypedArray,
+}
+
+#[wasm_bindgen_test]
+fn test_preserve_round_trip() {
+    let array = TypedArray::from(Float32Array::new_with_length(4));
+    let message = Message {
+        array: array.clone(),
+    };
+    let value = serde_wasm_bindgen::to_value(&message).unwrap();
+    assert_eq!(
+        Reflect::get(&value, &JsValue::from("array")).unwrap(),
+        *AsRef::<JsValue>::as_ref(&array)
+    );
+    let message: Message = serde_wasm_bindgen::from_value(value).unwrap();
+    assert_eq!(message.array.kind(), TypedArrayKind::Float32Array);
+    assert!(message.array.same_buffer_as(&array));
+}
+
+#[wasm_bindgen_test]
+fn test_preserve_invalid() {
+    let value = Object::new();
+    Reflect::set(&value, &JsValue::from("array"), &Array::new()).unwrap();
+    assert!(serde_wasm_bindgen::from_value::<Message>(value.into()).is_err());
+}
@@ -0,0 +1,85 @@
+#![cfg(feature = "serde")]
+
+use js_sys::*;
+use serde::de::value::{Error, MapDeserializer, SeqDeserializer};
+use serde::de::{Deserializer, IntoDeserializer, Visitor};
+use serde::{forward_to_deserialize_any, Deserialize};
+use typed_array::{TypedArray, TypedArrayKind};
+use wasm_bindgen_test::*;
+
+/// A field of a serialized typed array.
+enum Value {
+    Str(&'static str),
+    Bytes(&'static [u8]),
+    Seq(&'static [u8]),
+}
+
+impl<'de> Deserializer<'de> for Value {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        match self {
+            Value::Str(s) => visitor.visit_str(s),
+            Value::Bytes(bytes) => visitor.visit_bytes(bytes),
+            Value::Seq(bytes) => visitor.visit_seq(SeqDeserializer::new(bytes.iter().copied())),
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de> IntoDeserializer<'de> for Value {
+    type Deserializer = Value;
+
+    fn into_deserializer(self) -> Value {
+        self
+    }
+}
+
+fn deserialize(kind: &'static str, bytes: &'static [u8]) -> Result<TypedArray, Error> {
+    let fields = vec![("kind", Value::Str(kind)), ("bytes", Value::Bytes(bytes))];
+    TypedArray::deserialize(MapDeserializer::new(fields.into_iter()))
+}
+
+#[wasm_bindgen_test]
+fn test_deserialize() {
+    let array = deserialize("Uint16Array", &[1, 0, 2, 1]).unwrap();
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![1.0, 258.0]);
+    assert!(TypedArray::from(Uint16Array::from(&[1, 258][..])).content_eq(&array));
+}
+
+#[wasm_bindgen_test]
+fn test_deserialize_bytes_as_sequence() {
+    let fields = vec![
+        ("kind", Value::Str("Float32Array")),
+        ("bytes", Value::Seq(&[0, 0, 128, 63])),
+    ];
+    let array = TypedArray::deserialize(MapDeserializer::new(fields.into_iter())).unwrap();
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![1.0]);
+}
+
+#[wasm_bindgen_test]
+fn test_deserialize_kind() {
+    let kind = TypedArrayKind::deserialize(Value::Str("Uint8ClampedArray")).unwrap();
+    assert_eq!(kind, TypedArrayKind::Uint8ClampedArray);
+    assert!(TypedArrayKind::deserialize(Value::Str("Array")).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_bytes() {
+    let array = typed_array::bytes::deserialize(Value::Bytes(&[1, 2, 255])).unwrap();
+    assert_eq!(array.kind(), TypedArrayKind::Uint8Array);
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![1.0, 2.0, 255.0]);
+    let array = typed_array::bytes::deserialize(Value::Seq(&[3])).unwrap();
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![3.0]);
+}
+
+#[wasm_bindgen_test]
+fn test_deserialize_invalid() {
+    assert!(deserialize("Uint16Array", &[1, 0, 2]).is_err());
+    assert!(deserialize("BigInt64Array", &[]).is_err());
+}
@@ -1,4 +1,5 @@
-use typed_array::TypedArray;
+use core::convert::TryFrom;
+use typed_array::{AtomicWaitResult, Base64Alphabet, BitOrder, BitReader, ConcatError, ConversionMode, GatherError, HashableTypedArray, NanMode, NonFiniteMode, ScratchArena, SharedTypedArray, StreamingDecoder, TypedArray, TypedArrayPool, TypedArrayKind, TypedArrayReader, TypedArrayWriter};
 use js_sys::*;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_test::*;
@@ -46,4 +47,1541 @@ fn test_length() {
         length_of_typed_array(Float64Array::new(&10.into())),
         10
     );
-}
\ No newline at end of file
+}
+
+#[wasm_bindgen_test]
+fn test_same_buffer_as() {
+    let array: TypedArray = Uint8Array::new_with_length(8).into();
+    assert!(array.same_buffer_as(&array.subarray(2, 4)));
+    assert!(array.same_buffer_as(&array.reinterpret_as(TypedArrayKind::Uint16Array).unwrap()));
+    assert!(!array.same_buffer_as(&array.slice(0, 8)));
+}
+
+#[wasm_bindgen_test]
+fn test_overlaps() {
+    let array: TypedArray = Uint16Array::new_with_length(8).into();
+    let bytes = array.as_bytes_typed_array();
+    assert!(array.subarray(0, 2).overlaps(&bytes.subarray(3, 6)));
+    assert!(!array.subarray(0, 2).overlaps(&bytes.subarray(4, 6)));
+    assert!(!array.subarray(2, 2).overlaps(&array));
+    assert!(!array.overlaps(&array.slice(0, 8)));
+}
+
+#[wasm_bindgen_test]
+fn test_slice_buffer() {
+    let array: TypedArray = Uint16Array::from(&[1, 2, 3, 4][..]).into();
+    let buffer = array.subarray(1, 3).slice_buffer();
+    assert_eq!(buffer.byte_length(), 4);
+    assert_eq!(Uint16Array::new(&buffer).to_vec(), vec![2, 3]);
+    Uint16Array::new(&buffer).fill(0, 0, 2);
+    assert_eq!(array.get_index(1), 2.0);
+}
+
+#[wasm_bindgen_test]
+fn test_duplicate() {
+    let array: TypedArray = Int32Array::from(&[1, 2, 3, 4][..]).into();
+    let view = array.subarray(1, 3);
+    let copy = view.duplicate();
+    let handle = view.clone();
+    array.fill(0.0, 0, 4);
+    assert_eq!(Int32Array::try_from(copy.clone()).unwrap().to_vec(), vec![2, 3]);
+    assert_eq!(handle.get_index(0), 0.0);
+    assert_eq!((copy.byte_offset(), copy.buffer().byte_length()), (0, 8));
+}
+
+#[wasm_bindgen_test]
+fn test_is_detached() {
+    let array: TypedArray = Float32Array::new_with_length(4).into();
+    let view = array.subarray(1, 3);
+    let empty: TypedArray = Uint8Array::new_with_length(0).into();
+    assert!(!array.is_detached());
+    assert!(!empty.is_detached());
+    let detach = Function::new_with_args("buffer", "structuredClone(buffer, { transfer: [buffer] })");
+    detach.call1(&JsValue::NULL, &array.buffer()).unwrap();
+    assert!(array.is_detached());
+    assert!(view.is_detached());
+    assert_eq!(view.length(), 0);
+}
+
+#[wasm_bindgen_test]
+fn test_transfer() {
+    let prototype = Reflect::get(&ArrayBuffer::new(0), &"__proto__".into()).unwrap();
+    for &fixed_length in &[false, true] {
+        let array: TypedArray = Int16Array::from(&[1, 2, 3, 4][..]).into();
+        let view = array.subarray(1, 3);
+        let result = if fixed_length {
+            view.transfer_to_fixed_length()
+        } else {
+            view.transfer()
+        };
+        let name = if fixed_length { "transferToFixedLength" } else { "transfer" };
+        if Reflect::has(&prototype, &name.into()).unwrap() {
+            let moved = result.unwrap();
+            assert!(array.is_detached());
+            assert_eq!(moved.kind(), TypedArrayKind::Int16Array);
+            assert_eq!((moved.byte_offset(), moved.length()), (2, 2));
+            assert_eq!(moved.get_index(0), 2.0);
+        } else {
+            assert!(result.is_err());
+            assert!(!array.is_detached());
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_resize_buffer() {
+    let fixed: TypedArray = Uint8Array::new(&4.into()).into();
+    assert!(!fixed.is_resizable());
+    assert_eq!(fixed.max_byte_length(), 4);
+    assert!(fixed.resize_buffer(8).is_err());
+
+    let buffer = ArrayBuffer::new_with_options(4, &ArrayBufferOptions::new(8));
+    let array: TypedArray = Uint8Array::new_with_byte_offset_and_length(&buffer, 0, 4).into();
+    if array.is_resizable() {
+        assert_eq!(array.max_byte_length(), 8);
+        array.resize_buffer(8).unwrap();
+        assert_eq!(array.buffer().byte_length(), 8);
+        assert_eq!(array.length(), 4);
+        assert!(array.resize_buffer(9).is_err());
+        array.resize_buffer(2).unwrap();
+        assert_eq!(array.length(), 0);
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_length_tracking() {
+    let buffer = ArrayBuffer::new_with_options(8, &ArrayBufferOptions::new(16));
+    let array = TypedArray::new_length_tracking(TypedArrayKind::Uint16Array, &buffer, 2);
+    assert_eq!(array.kind(), TypedArrayKind::Uint16Array);
+    assert_eq!(array.snapshot_length(), 3);
+    if array.is_resizable() {
+        array.resize_buffer(16).unwrap();
+        assert_eq!(array.length(), 7);
+        let mut chunks = array.iter_chunks(4);
+        array.resize_buffer(6).unwrap();
+        assert_eq!(chunks.next_chunk().map(<[f64]>::len), Some(2));
+        assert!(chunks.next_chunk().is_none());
+        assert_eq!(array.length(), 2);
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_shared() {
+    let array: TypedArray = Uint8Array::new(&4.into()).into();
+    assert!(!array.is_shared());
+    assert!(!array.backing_buffer().is_shared());
+    if Reflect::has(&global(), &"SharedArrayBuffer".into()).unwrap() {
+        let buffer = SharedArrayBuffer::new(8);
+        let view = TypedArray::from_shared(TypedArrayKind::Int16Array, &buffer, 2, 2);
+        assert!(view.is_shared());
+        assert_eq!((view.byte_offset(), view.length()), (2, 2));
+        let backing = view.backing_buffer();
+        assert!(backing.is_shared());
+        assert_eq!(backing.byte_length(), 8);
+        assert!(Object::is(backing.as_ref(), buffer.as_ref()));
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_grow_shared() {
+    let array: TypedArray = Uint8Array::new(&4.into()).into();
+    assert!(!array.is_growable());
+    assert!(array.grow_shared(8).is_err());
+    if Reflect::has(&global(), &"SharedArrayBuffer".into()).unwrap() {
+        let buffer = SharedArrayBuffer::new_with_options(4, &ArrayBufferOptions::new(8));
+        let fixed = TypedArray::from_shared(TypedArrayKind::Uint8Array, &buffer, 0, 4);
+        let tracking = TypedArray::from_shared_length_tracking(TypedArrayKind::Uint16Array, &buffer, 0);
+        assert_eq!(tracking.length(), 2);
+        if tracking.is_growable() {
+            assert_eq!(tracking.max_byte_length(), 8);
+            tracking.grow_shared(8).unwrap();
+            assert_eq!((fixed.length(), tracking.length()), (4, 4));
+            assert!(tracking.grow_shared(6).is_err());
+            assert!(tracking.grow_shared(10).is_err());
+        }
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_atomics() {
+    let array: TypedArray = Int32Array::from(&[5, 0b1100][..]).into();
+    assert_eq!(array.atomic_load(0), Ok(5));
+    assert_eq!(array.atomic_add(0, 3), Ok(5));
+    assert_eq!(array.atomic_sub(0, 1), Ok(8));
+    assert_eq!(array.atomic_store(0, 9), Ok(9));
+    assert_eq!(array.atomic_and(1, 0b1010), Ok(0b1100));
+    assert_eq!(array.atomic_or(1, 0b0001), Ok(0b1000));
+    assert_eq!(array.atomic_xor(1, 0b1111), Ok(0b1001));
+    assert_eq!(array.atomic_exchange(1, 2), Ok(0b0110));
+    assert_eq!(array.atomic_compare_exchange(1, 3, 4), Ok(2));
+    assert_eq!(array.atomic_compare_exchange(1, 2, 4), Ok(2));
+    assert_eq!(array.get_index(1), 4.0);
+
+    let bytes: TypedArray = Uint8Array::new(&1.into()).into();
+    assert_eq!(bytes.atomic_sub(0, 1), Ok(0));
+    assert_eq!(bytes.atomic_load(0), Ok(255));
+
+    let floats: TypedArray = Float32Array::new(&1.into()).into();
+    let error = floats.atomic_add(0, 1).unwrap_err();
+    assert_eq!(error.kind(), TypedArrayKind::Float32Array);
+    assert!(TypedArray::from(Uint8ClampedArray::new(&1.into())).atomic_load(0).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_atomic_wait() {
+    let array: TypedArray = Int32Array::new(&1.into()).into();
+    assert!(array.atomic_wait(0, 0, Some(0.0)).is_err());
+    assert_eq!(array.atomic_notify(0, None), Ok(0));
+    let bytes: TypedArray = Uint8Array::new(&1.into()).into();
+    assert!(bytes.atomic_notify(0, Some(1)).is_err());
+    if Reflect::has(&global(), &"SharedArrayBuffer".into()).unwrap() {
+        let buffer = SharedArrayBuffer::new(4);
+        let shared = TypedArray::from_shared(TypedArrayKind::Int32Array, &buffer, 0, 1);
+        assert_eq!(shared.atomic_wait(0, 1, None), Ok(AtomicWaitResult::NotEqual));
+        assert_eq!(shared.atomic_wait(0, 0, Some(0.0)), Ok(AtomicWaitResult::TimedOut));
+        assert_eq!(shared.atomic_notify(0, Some(1)), Ok(0));
+        let atomics = Reflect::get(&global(), &"Atomics".into()).unwrap();
+        let supports_async = Reflect::has(&atomics, &"waitAsync".into()).unwrap();
+        assert_eq!(shared.atomic_wait_async(0, 1, None).is_ok(), supports_async);
+    }
+    assert_eq!(AtomicWaitResult::from_js_value(&"ok".into()), Some(AtomicWaitResult::Ok));
+    assert_eq!(AtomicWaitResult::from_js_value(&1.into()), None);
+}
+
+#[wasm_bindgen_test]
+fn test_shared_typed_array() {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SharedTypedArray>();
+
+    let array: TypedArray = Uint8Array::new(&4.into()).into();
+    assert!(SharedTypedArray::new(&array).is_err());
+    let memory: WebAssembly::Memory = wasm_bindgen::memory().unchecked_into();
+    let in_memory = TypedArray::from(Float32Array::new_with_byte_offset_and_length(&memory.buffer(), 16, 2));
+    match SharedTypedArray::new(&in_memory) {
+        Ok(shared) => {
+            assert_eq!((shared.kind(), shared.byte_offset(), shared.length()), (TypedArrayKind::Float32Array, 16, 2));
+            shared.view().fill(1.5, 0, 2);
+            assert_eq!(in_memory.get_index(1), 1.5);
+        }
+        Err(_) => assert!(!in_memory.is_shared()),
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_pool() {
+    let pool = TypedArrayPool::new();
+    let first = pool.acquire(TypedArrayKind::Float32Array, 3);
+    assert_eq!((first.kind(), first.length()), (TypedArrayKind::Float32Array, 3));
+    first.fill(1.0, 0, 3);
+    let buffer = first.buffer();
+    drop(first);
+    assert_eq!(pool.free_buffers(), 1);
+
+    let second = pool.acquire(TypedArrayKind::Uint16Array, 5);
+    assert_eq!(pool.free_buffers(), 0);
+    assert!(Object::is(second.buffer().as_ref(), buffer.as_ref()));
+    assert_eq!(second.iter().collect::<Vec<_>>(), vec![0.0; 5]);
+    let kept = second.into_inner();
+    assert_eq!(kept.length(), 5);
+    assert_eq!(pool.free_buffers(), 0);
+
+    let large = pool.acquire(TypedArrayKind::Float64Array, 100);
+    assert!(!Object::is(large.buffer().as_ref(), buffer.as_ref()));
+    drop(large);
+    pool.clear();
+    assert_eq!(pool.free_buffers(), 0);
+}
+
+#[wasm_bindgen_test]
+fn test_scratch_arena() {
+    let arena = ScratchArena::new(16);
+    assert_eq!(arena.byte_capacity(), 16);
+    let bytes = arena.alloc(TypedArrayKind::Uint8Array, 3).unwrap();
+    let floats = arena.alloc(TypedArrayKind::Float32Array, 2).unwrap();
+    assert_eq!(bytes.byte_offset(), 0);
+    assert_eq!((floats.byte_offset(), floats.length()), (4, 2));
+    assert_eq!(arena.used(), 12);
+    assert!(floats.same_buffer_as(&bytes));
+    let error = arena.alloc(TypedArrayKind::Float64Array, 1).unwrap_err();
+    assert_eq!((error.requested(), error.available()), (12, 4));
+    assert_eq!(arena.epoch(), 0);
+    arena.reset();
+    assert_eq!((arena.epoch(), arena.used()), (1, 0));
+    let doubles = arena.alloc(TypedArrayKind::Float64Array, 2).unwrap();
+    assert_eq!(doubles.byte_length(), 16);
+}
+
+#[wasm_bindgen_test]
+fn test_allocation_limit() {
+    assert_eq!(typed_array::allocation_limit(), None);
+    typed_array::set_allocation_limit(Some(16));
+    assert!(TypedArray::try_new_with_length(TypedArrayKind::Float64Array, 2).is_ok());
+    let error = TypedArray::try_new_with_length(TypedArrayKind::Float64Array, 3).unwrap_err();
+    assert_eq!((error.requested(), error.limit()), (24, 16));
+    assert!(TypedArray::try_new_with_length(TypedArrayKind::Uint8Array, u32::MAX).is_err());
+    typed_array::set_allocation_limit(None);
+    assert!(TypedArray::try_new_with_length(TypedArrayKind::Float64Array, 3).is_ok());
+}
+
+#[wasm_bindgen_test]
+fn test_allocation_limit_decoding() {
+    let array: TypedArray = Float64Array::from(&[1.0, 2.0, 3.0][..]).into();
+    let encoded = array.encode_binary();
+    typed_array::set_allocation_limit(Some(16));
+    let binary = TypedArray::decode_binary(&encoded);
+    let hex = TypedArray::from_hex(TypedArrayKind::Uint8Array, "000102030405060708090a0b0c0d0e0f10");
+    let json = TypedArray::from_json_str(TypedArrayKind::Float64Array, "[1,2,3]", NonFiniteMode::Null);
+    typed_array::set_allocation_limit(None);
+    let error = binary.unwrap_err().allocation_limit_error().unwrap();
+    assert_eq!((error.requested(), error.limit()), (24, 16));
+    assert!(hex.unwrap_err().allocation_limit_error().is_some());
+    assert!(json.unwrap_err().allocation_limit_error().is_some());
+    assert!(TypedArray::decode_binary(&encoded[..20]).unwrap_err().allocation_limit_error().is_none());
+}
+
+#[wasm_bindgen_test]
+fn test_allocation_limit_constructors() {
+    let array: TypedArray = Float64Array::from(&[1.0, 2.0][..]).into();
+    typed_array::set_allocation_limit(Some(8));
+    let repeated = array.try_repeat(2);
+    let concatenated = TypedArray::concat(&[array.clone(), array.clone()]);
+    let narrowed = array.try_convert_to(TypedArrayKind::Float32Array, ConversionMode::Truncate);
+    let converted = array.try_convert_to(TypedArrayKind::Float64Array, ConversionMode::Truncate);
+    let encoded = TypedArray::try_from_str_utf8("nine byte");
+    typed_array::set_allocation_limit(None);
+    let error = repeated.unwrap_err();
+    assert_eq!((error.requested(), error.limit()), (32, 8));
+    assert!(matches!(concatenated, Err(ConcatError::AllocationLimit(_))));
+    assert!(narrowed.is_ok());
+    assert_eq!(converted.unwrap_err().requested(), 16);
+    assert_eq!(encoded.unwrap_err().requested(), 9);
+}
+
+#[wasm_bindgen_test]
+fn test_content_eq() {
+    let array: TypedArray = Float32Array::from(&[1.0, f32::NAN, 3.0][..]).into();
+    assert!(array.content_eq(&array));
+    assert!(array.content_eq(&array.duplicate()));
+    assert!(!array.content_eq(&array.subarray(0, 2)));
+    let other: TypedArray = Float32Array::from(&[1.0, f32::NAN, 4.0][..]).into();
+    assert!(!array.content_eq(&other));
+    assert!(array.subarray(0, 2).content_eq(&other.subarray(0, 2)));
+    let ints: TypedArray = Int32Array::new(&3.into()).into();
+    assert!(!ints.content_eq(&TypedArray::from(Uint32Array::new(&3.into()))));
+    let zero: TypedArray = Float64Array::from(&[0.0][..]).into();
+    assert!(!zero.content_eq(&TypedArray::from(Float64Array::from(&[-0.0][..]))));
+}
+
+#[wasm_bindgen_test]
+fn test_cmp_contents() {
+    use std::cmp::Ordering;
+    let array: TypedArray = Int8Array::from(&[1, -2, 3][..]).into();
+    assert_eq!(array.cmp_contents(&array.duplicate()), Some(Ordering::Equal));
+    assert_eq!(array.subarray(0, 2).cmp_contents(&array), Some(Ordering::Less));
+    let other: TypedArray = Int8Array::from(&[1, -1][..]).into();
+    assert_eq!(array.cmp_contents(&other), Some(Ordering::Less));
+    assert_eq!(array.cmp_contents(&TypedArray::from(Uint8Array::new(&3.into()))), None);
+    let floats: TypedArray = Float64Array::from(&[0.0, f64::NAN][..]).into();
+    let other: TypedArray = Float64Array::from(&[-0.0, 1.0][..]).into();
+    assert_eq!(floats.cmp_contents(&other), Some(Ordering::Greater));
+    assert_eq!(floats.cmp_contents(&floats), Some(Ordering::Equal));
+}
+
+#[wasm_bindgen_test]
+fn test_hash_contents() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::collections::HashSet;
+    use std::hash::Hasher;
+    let hash = |array: &TypedArray| {
+        let mut hasher = DefaultHasher::new();
+        array.hash_contents(&mut hasher);
+        hasher.finish()
+    };
+    let array: TypedArray = Uint16Array::from(&[1, 2, 3][..]).into();
+    assert_eq!(hash(&array), hash(&array.duplicate()));
+    let mut set = HashSet::new();
+    assert!(set.insert(HashableTypedArray::new(array.clone())));
+    assert!(!set.insert(HashableTypedArray::new(array.duplicate())));
+    assert!(set.insert(HashableTypedArray::new(array.subarray(0, 2))));
+    assert!(set.insert(HashableTypedArray::new(Int16Array::from(&[1, 2, 3][..]).into())));
+    assert_eq!(set.len(), 3);
+}
+
+#[wasm_bindgen_test]
+fn test_display() {
+    let array: TypedArray = Float64Array::from(&[1.0, -0.5, f64::INFINITY][..]).into();
+    assert_eq!(array.to_string(), "1,-0.5,Infinity");
+    assert_eq!(TypedArray::from(Uint8Array::new(&0.into())).to_string(), "");
+}
+
+#[wasm_bindgen_test]
+fn test_debug() {
+    let array: TypedArray = Int16Array::from(&[1, -2, 3][..]).into();
+    assert_eq!(format!("{:?}", array), "Int16Array(len=3) [1, -2, 3]");
+    let array: TypedArray = Float32Array::from(&[0.0, 0.5, 0.25, 1.0][..]).into();
+    assert_eq!(
+        format!("{:?}", array.debug_preview(3)),
+        "Float32Array(len=4) [0.0, 0.5, …, 1.0]"
+    );
+    assert_eq!(format!("{:?}", array.debug_preview(0)), "Float32Array(len=4) […]");
+}
+
+#[wasm_bindgen_test]
+fn test_hex() {
+    let bytes: TypedArray = Uint8Array::from(&[0, 127, 255][..]).into();
+    assert_eq!(format!("{:x}", bytes), "00 7f ff");
+    assert_eq!(format!("{:#X}", bytes), "0x00 0x7F 0xFF");
+    let ints: TypedArray = Int16Array::from(&[-1, 10][..]).into();
+    assert_eq!(format!("{:X}", ints), "FFFF 000A");
+    let floats: TypedArray = Float32Array::from(&[1.0][..]).into();
+    assert_eq!(format!("{:x}", floats), "3f800000");
+}
+
+#[wasm_bindgen_test]
+fn test_diff() {
+    let array: TypedArray = Float32Array::from(&[1.0, 2.0, f32::NAN, 4.0, 5.0][..]).into();
+    let report = array.diff(&array.duplicate());
+    assert!(report.is_equal());
+    assert_eq!(report.first_mismatch(), None);
+    let other: TypedArray = Float64Array::from(&[1.0, 0.0, f64::NAN, 0.0, 0.0, 6.0][..]).into();
+    let report = array.diff_with_ranges(&other);
+    assert!(!report.is_equal());
+    assert_eq!(report.first_mismatch(), Some(1));
+    assert_eq!(report.mismatches(), 4);
+    assert_eq!(report.ranges(), Some(&[1..2, 3..6][..]));
+    assert_eq!(array.diff(&other).ranges(), None);
+}
+
+#[wasm_bindgen_test]
+fn test_xor_with() {
+    let array: TypedArray = Float32Array::from(&[1.0, -2.5][..]).into();
+    let original = array.duplicate();
+    let patch: TypedArray = Float32Array::from(&[3.0, 4.0][..]).into();
+    array.xor_with(&patch).unwrap();
+    assert!(!array.content_eq(&original));
+    array.xor_with(&patch).unwrap();
+    assert!(array.content_eq(&original));
+    assert!(array.xor_with(&TypedArray::from(Int32Array::new(&2.into()))).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_apply_delta() {
+    let array: TypedArray = Uint8Array::from(&[250, 1, 2][..]).into();
+    let delta: TypedArray = Uint8Array::from(&[10, 255, 0][..]).into();
+    array.apply_delta(&delta).unwrap();
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![4.0, 0.0, 2.0]);
+    let floats: TypedArray = Float64Array::from(&[0.5][..]).into();
+    floats.apply_delta(&Float64Array::from(&[0.25][..]).into()).unwrap();
+    assert_eq!(floats.iter().collect::<Vec<_>>(), vec![0.75]);
+    assert!(array.apply_delta(&floats).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_approx_eq() {
+    let array: TypedArray = Float32Array::from(&[0.1, 1000.0, f32::NAN][..]).into();
+    let other: TypedArray = Float64Array::from(&[0.1, 1000.5, f64::NAN][..]).into();
+    assert!(!array.approx_eq(&other, 0.1));
+    assert!(array.approx_eq(&other, 1.0));
+    assert!(array.approx_eq_relative(&other, 1e-3));
+    assert!(!array.approx_eq_relative(&other, 1e-6));
+    assert!(!array.approx_eq(&array.subarray(0, 2), 1.0));
+}
+
+#[wasm_bindgen_test]
+fn test_non_finite() {
+    let array: TypedArray = Float32Array::from(&[1.0, f32::NEG_INFINITY, f32::NAN][..]).into();
+    assert!(array.contains_nan());
+    assert!(array.contains_infinite());
+    assert_eq!(array.find_first_non_finite(), Some(1));
+    let finite = array.subarray(0, 1);
+    assert!(!finite.contains_nan());
+    assert!(!finite.contains_infinite());
+    assert_eq!(finite.find_first_non_finite(), None);
+    assert_eq!(TypedArray::from(Int32Array::new(&4.into())).find_first_non_finite(), None);
+}
+
+#[wasm_bindgen_test]
+fn test_hash_chunks() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::Hasher;
+    let array: TypedArray = Uint32Array::from(&[1, 2, 3, 4, 5][..]).into();
+    let mut whole = DefaultHasher::new();
+    array.hash_contents(&mut whole);
+    let mut chunked = DefaultHasher::new();
+    array.hash_chunks(3, &mut chunked);
+    assert_eq!(whole.finish(), chunked.finish());
+    let mut chunks = Vec::new();
+    array.digest_streaming(8, |chunk| chunks.push(chunk.len()));
+    assert_eq!(chunks, vec![8, 8, 4]);
+}
+
+#[cfg(feature = "watch")]
+#[wasm_bindgen_test]
+fn test_watched() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    let writes = Rc::new(RefCell::new(Vec::new()));
+    let recorded = writes.clone();
+    let array: TypedArray = Uint8Array::new(&4.into()).into();
+    let watched = array
+        .clone()
+        .watched(move |index, value| recorded.borrow_mut().push((index, value)));
+    assert_eq!(watched.length(), 4);
+    assert!(watched.same_buffer_as(&array));
+    Reflect::set(watched.as_ref(), &1.into(), &300.into()).unwrap();
+    Reflect::set(watched.as_ref(), &10.into(), &1.into()).unwrap();
+    Reflect::set(watched.as_ref(), &"name".into(), &1.into()).unwrap();
+    array.fill(7.0, 2, 3);
+    assert_eq!(*writes.borrow(), vec![(1, 44.0)]);
+    assert_eq!(array.get_index(1), 44.0);
+}
+
+#[wasm_bindgen_test]
+fn test_to_json_string() {
+    let array: TypedArray = Float32Array::from(&[0.1, -2.0, 1e-7, f32::NAN, f32::INFINITY][..]).into();
+    assert_eq!(array.to_json_string(NonFiniteMode::Null).unwrap(), "[0.1,-2,1e-7,null,null]");
+    assert_eq!(
+        array.to_json_string(NonFiniteMode::String).unwrap(),
+        "[0.1,-2,1e-7,\"NaN\",\"Infinity\"]"
+    );
+    assert_eq!(array.to_json_string(NonFiniteMode::Error).unwrap_err().index(), 3);
+    let empty: TypedArray = Uint8Array::new(&0.into()).into();
+    assert_eq!(empty.to_json_string(NonFiniteMode::Error).unwrap(), "[]");
+}
+
+#[wasm_bindgen_test]
+fn test_from_json_str() {
+    let array = TypedArray::from_json_str(TypedArrayKind::Int16Array, " [1, -2.5e1 ,3]\n", NonFiniteMode::Error).unwrap();
+    assert_eq!(array.kind(), TypedArrayKind::Int16Array);
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![1.0, -25.0, 3.0]);
+    let array = TypedArray::from_json_str(TypedArrayKind::Float64Array, "[null,\"-Infinity\"]", NonFiniteMode::Null);
+    assert_eq!(array.unwrap_err().position(), 6);
+    let array = TypedArray::from_json_str(TypedArrayKind::Float64Array, "[\"-Infinity\"]", NonFiniteMode::String).unwrap();
+    assert_eq!(array.get_index(0), f64::NEG_INFINITY);
+    assert!(TypedArray::from_json_str(TypedArrayKind::Uint8Array, "[]", NonFiniteMode::Null).unwrap().is_empty());
+    assert!(TypedArray::from_json_str(TypedArrayKind::Uint8Array, "[01]", NonFiniteMode::Null).is_err());
+    assert!(TypedArray::from_json_str(TypedArrayKind::Uint8Array, "[1,]", NonFiniteMode::Null).is_err());
+}
+
+#[cfg(feature = "cbor")]
+#[wasm_bindgen_test]
+fn test_cbor() {
+    let array: TypedArray = Float32Array::from(&[1.0][..]).into();
+    assert_eq!(array.to_cbor(), vec![0xd8, 0x55, 0x44, 0x00, 0x00, 0x80, 0x3f]);
+    assert!(TypedArray::from_cbor(&array.to_cbor()).unwrap().content_eq(&array));
+    let big_endian = TypedArray::from_cbor(&[0xd8, 0x51, 0x44, 0x3f, 0x80, 0x00, 0x00]).unwrap();
+    assert!(big_endian.content_eq(&array));
+    let long: TypedArray = Uint16Array::new(&300.into()).into();
+    assert!(TypedArray::from_cbor(&long.to_cbor()).unwrap().content_eq(&long));
+    let untagged = TypedArray::from_cbor(&[0x42, 1, 2]).unwrap();
+    assert_eq!(untagged.kind(), TypedArrayKind::Uint8Array);
+    assert_eq!(TypedArray::from_cbor(&[0xd8, 0x55, 0x43, 0, 0, 0]).unwrap_err().position(), 3);
+    assert!(TypedArray::from_cbor(&[0xd8, 0x57, 0x40]).is_err());
+}
+
+#[cfg(feature = "msgpack")]
+#[wasm_bindgen_test]
+fn test_msgpack() {
+    let array: TypedArray = Float32Array::from(&[1.0][..]).into();
+    assert_eq!(array.to_msgpack(), vec![0xc7, 0x04, 0x55, 0x00, 0x00, 0x80, 0x3f]);
+    assert!(TypedArray::from_msgpack(&array.to_msgpack()).unwrap().content_eq(&array));
+    let fixext = TypedArray::from_msgpack(&[0xd6, 0x55, 0x00, 0x00, 0x80, 0x3f]).unwrap();
+    assert!(fixext.content_eq(&array));
+    let long: TypedArray = Int32Array::new(&100.into()).into();
+    assert!(TypedArray::from_msgpack(&long.to_msgpack()).unwrap().content_eq(&long));
+    let bin = TypedArray::from_msgpack(&[0xc4, 0x02, 1, 2]).unwrap();
+    assert_eq!(bin.kind(), TypedArrayKind::Uint8Array);
+    assert!(TypedArray::from_msgpack(&[0xc4, 0x02, 1]).is_err());
+}
+
+#[cfg(feature = "npy")]
+#[wasm_bindgen_test]
+fn test_npy() {
+    let array: TypedArray = Int16Array::from(&[1, -2][..]).into();
+    let npy = array.to_npy_bytes();
+    assert_eq!(&npy[..8], b"\x93NUMPY\x01\x00");
+    assert_eq!(npy.len(), 128 + 4);
+    let header = core::str::from_utf8(&npy[10..128]).unwrap();
+    assert!(header.starts_with("{'descr': '<i2', 'fortran_order': False, 'shape': (2,), }"));
+    assert!(header.ends_with('\n'));
+    assert!(TypedArray::from_npy_bytes(&npy).unwrap().content_eq(&array));
+
+    let header = b"{'descr': '>f4', 'fortran_order': False, 'shape': (1, 2), }\n";
+    let mut big_endian = b"\x93NUMPY\x01\x00".to_vec();
+    big_endian.extend_from_slice(&(header.len() as u16).to_le_bytes());
+    big_endian.extend_from_slice(header);
+    big_endian.extend_from_slice(&[0x3f, 0x80, 0, 0, 0x40, 0, 0, 0]);
+    let decoded = TypedArray::from_npy_bytes(&big_endian).unwrap();
+    assert_eq!(decoded.kind(), TypedArrayKind::Float32Array);
+    assert_eq!(decoded.iter().collect::<Vec<_>>(), vec![1.0, 2.0]);
+    big_endian.pop();
+    assert!(TypedArray::from_npy_bytes(&big_endian).is_err());
+    assert_eq!(TypedArray::from_npy_bytes(b"NUMPY").unwrap_err().position(), 0);
+    assert!(TypedArray::from_npy_bytes(b"\x93NUMPY\x02\x00\xff\xff\xff\xff").is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_encode_binary() {
+    let array: TypedArray = Int16Array::from(&[1, -2][..]).into();
+    let encoded = array.encode_binary();
+    assert_eq!(encoded, b"TYAR\x01\x03\x00\x00\x02\x00\x00\x00\x00\x00\x00\x00\x01\x00\xfe\xff".to_vec());
+    assert!(TypedArray::decode_binary(&encoded).unwrap().content_eq(&array));
+    let mut big_endian = encoded.clone();
+    big_endian[6] = 1;
+    big_endian[16..].copy_from_slice(&[0x00, 0x01, 0xff, 0xfe]);
+    assert!(TypedArray::decode_binary(&big_endian).unwrap().content_eq(&array));
+    assert_eq!(TypedArray::decode_binary(&encoded[..17]).unwrap_err().position(), 8);
+    let mut reserved = encoded.clone();
+    reserved[7] = 1;
+    assert_eq!(TypedArray::decode_binary(&reserved).unwrap_err().position(), 7);
+    assert_eq!(TypedArray::decode_binary(b"TYAR").unwrap_err().position(), 4);
+}
+
+#[wasm_bindgen_test]
+fn test_base64() {
+    let array: TypedArray = Uint8Array::from(&b"fooba"[..]).into();
+    assert_eq!(array.to_base64(Base64Alphabet::Standard), "Zm9vYmE=");
+    assert_eq!(array.to_base64(Base64Alphabet::UrlSafe), "Zm9vYmE");
+    let decoded = TypedArray::from_base64(TypedArrayKind::Uint8Array, "Zm9vYmE", Base64Alphabet::Standard).unwrap();
+    assert!(decoded.content_eq(&array));
+    let bytes: TypedArray = Uint8Array::from(&[0xfb, 0xff][..]).into();
+    assert_eq!(bytes.to_base64(Base64Alphabet::Standard), "+/8=");
+    assert_eq!(bytes.to_base64(Base64Alphabet::UrlSafe), "-_8");
+    let decoded = TypedArray::from_base64(TypedArrayKind::Uint16Array, "-_8=", Base64Alphabet::UrlSafe).unwrap();
+    assert_eq!(decoded.length(), 1);
+    assert_eq!(TypedArray::from_base64(TypedArrayKind::Uint16Array, "Zm9vYmE=", Base64Alphabet::Standard).unwrap_err().position(), 8);
+    assert_eq!(TypedArray::from_base64(TypedArrayKind::Uint8Array, "Zm9v-A==", Base64Alphabet::Standard).unwrap_err().position(), 4);
+}
+
+#[wasm_bindgen_test]
+fn test_hex_string() {
+    let array: TypedArray = Uint8Array::from(&[0x00, 0xff, 0x7a, 0x10][..]).into();
+    assert_eq!(array.to_hex(), "00ff7a10");
+    assert_eq!(array.to_hex_upper(), "00FF7A10");
+    let decoded = TypedArray::from_hex(TypedArrayKind::Uint16Array, "00Ff7a10").unwrap();
+    assert_eq!(decoded.kind(), TypedArrayKind::Uint16Array);
+    assert!(decoded.as_bytes_typed_array().content_eq(&array));
+    assert_eq!(TypedArray::from_hex(TypedArrayKind::Uint8Array, "0f0").unwrap_err().position(), 3);
+    assert_eq!(TypedArray::from_hex(TypedArrayKind::Uint8Array, "0g").unwrap_err().position(), 1);
+    assert_eq!(TypedArray::from_hex(TypedArrayKind::Int32Array, "0011").unwrap_err().position(), 4);
+}
+
+#[wasm_bindgen_test]
+fn test_data_url() {
+    let array: TypedArray = Uint8Array::from(&b"foo"[..]).into();
+    assert_eq!(array.to_data_url("text/plain"), "data:text/plain;base64,Zm9v");
+    assert!(TypedArray::from_data_url(&array.to_data_url("text/plain")).unwrap().content_eq(&array));
+    let decoded = TypedArray::from_data_url("DATA:,f%6Fo").unwrap();
+    assert!(decoded.content_eq(&array));
+    assert_eq!(TypedArray::from_data_url("data:;base64,Zm9v!").unwrap_err().position(), 17);
+    assert_eq!(TypedArray::from_data_url("data:,%6").unwrap_err().position(), 6);
+    assert_eq!(TypedArray::from_data_url("data:,%+1").unwrap_err().position(), 6);
+    assert!(TypedArray::from_data_url("http://example.com").is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_wav() {
+    let array: TypedArray = Int16Array::from(&[1, -1, 2, -2][..]).into();
+    let wav = array.to_wav_bytes(8000, 2).unwrap();
+    assert_eq!(wav.len(), 44 + 8);
+    assert_eq!(&wav[0..4], b"RIFF");
+    assert_eq!(&wav[8..16], b"WAVEfmt ");
+    assert_eq!(&wav[36..40], b"data");
+    let audio = TypedArray::from_wav_bytes(&wav).unwrap();
+    assert_eq!(audio.sample_rate(), 8000);
+    assert_eq!(audio.channels(), 2);
+    assert!(audio.samples().content_eq(&array));
+
+    let float: TypedArray = Float32Array::from(&[0.5, -0.5, 1.0][..]).into();
+    let audio = TypedArray::from_wav_bytes(&float.to_wav_bytes(44100, 1).unwrap()).unwrap();
+    assert!(audio.into_samples().content_eq(&float));
+
+    let int8: TypedArray = Int8Array::new(&2.into()).into();
+    assert_eq!(int8.to_wav_bytes(8000, 1).unwrap_err().kind(), TypedArrayKind::Int8Array);
+    assert!(TypedArray::from_wav_bytes(&wav[..40]).is_err());
+    assert_eq!(TypedArray::from_wav_bytes(b"RIFX").unwrap_err().position(), 0);
+}
+
+#[wasm_bindgen_test]
+fn test_varint() {
+    let array: TypedArray = Uint8Array::new(&12.into()).into();
+    assert_eq!(array.write_varint_at(0, 300), 2);
+    assert_eq!(array.write_zigzag_varint_at(2, -2), 1);
+    assert_eq!(array.get_index(0), 172.0);
+    assert_eq!(array.get_index(1), 2.0);
+    assert_eq!(array.read_varint_at(0).unwrap(), (300, 2));
+    assert_eq!(array.read_zigzag_varint_at(2).unwrap(), (-2, 1));
+    assert_eq!(array.write_varint_at(2, u64::MAX), 10);
+    assert_eq!(array.read_varint_at(2).unwrap(), (u64::MAX, 10));
+    assert_eq!(array.read_varint_at(3).unwrap_err().position(), 3);
+
+    let mut writer = TypedArrayWriter::new(&array);
+    writer.write_varint(1).unwrap();
+    writer.write_zigzag_varint(i64::MIN).unwrap();
+    assert!(writer.write_varint(u64::MAX).is_err());
+    array.as_bytes().set_index(11, 0x80);
+    let mut reader = TypedArrayReader::new(&array);
+    assert_eq!(reader.read_varint().unwrap(), 1);
+    assert_eq!(reader.read_zigzag_varint().unwrap(), i64::MIN);
+    assert_eq!(reader.position(), 11);
+    assert!(reader.read_varint().is_err());
+    assert_eq!(reader.position(), 11);
+}
+
+#[wasm_bindgen_test]
+fn test_decode_utf8() {
+    let array: TypedArray = Uint8Array::from("\u{feff}héllo".as_bytes()).into();
+    assert_eq!(array.decode_utf8().unwrap(), "héllo");
+    assert_eq!(array.decode_utf8_range(3..6).unwrap(), "hé");
+    assert_eq!(array.decode_utf8_range(6..).unwrap(), "llo");
+    assert!(array.decode_utf8_range(3..4).is_ok());
+    assert!(array.decode_utf8_range(5..6).is_err());
+    let invalid: TypedArray = Uint8Array::from(&[b'a', 0xff, b'b'][..]).into();
+    assert!(invalid.decode_utf8().is_err());
+    assert_eq!(invalid.decode_utf8_lossy(), "a\u{fffd}b");
+}
+
+#[wasm_bindgen_test]
+fn test_from_str_utf8() {
+    let array = TypedArray::from_str_utf8("héllo");
+    assert_eq!(array.kind(), TypedArrayKind::Uint8Array);
+    assert_eq!(array.decode_utf8().unwrap(), "héllo");
+    let existing: TypedArray = Uint8Array::new(&2.into()).into();
+    assert_eq!(existing.encode_into_existing("héllo"), 1);
+    assert_eq!(existing.get_index(0), f64::from(b'h'));
+    let existing: TypedArray = Uint16Array::new(&4.into()).into();
+    assert_eq!(existing.encode_into_existing("héllo"), 6);
+    assert_eq!(existing.encode_into_existing("hé"), 3);
+}
+
+#[wasm_bindgen_test]
+fn test_streaming_decoder() {
+    let bytes = "héllo".as_bytes();
+    let mut decoder = StreamingDecoder::new();
+    assert_eq!(decoder.decode(&Uint8Array::from(&bytes[..2]).into()).unwrap(), "h");
+    assert_eq!(decoder.decode(&Uint8Array::from(&bytes[2..]).into()).unwrap(), "éllo");
+    assert_eq!(decoder.finish().unwrap(), "");
+    assert_eq!(decoder.decode(&Uint8Array::from(&bytes[..2]).into()).unwrap(), "h");
+    assert!(decoder.finish().is_err());
+    let mut lossy = StreamingDecoder::new_lossy();
+    assert_eq!(lossy.decode(&Uint8Array::from(&bytes[..2]).into()).unwrap(), "h");
+    assert_eq!(lossy.finish().unwrap(), "\u{fffd}");
+}
+
+#[wasm_bindgen_test]
+fn test_decode_utf16() {
+    let units: Vec<u16> = "h€𝄞".encode_utf16().collect();
+    let array: TypedArray = Uint16Array::from(&units[..]).into();
+    assert_eq!(array.decode_utf16().unwrap(), "h€𝄞");
+    let bytes: TypedArray = Uint8Array::from(&[b'h', 0, b'i', 0][..]).into();
+    assert_eq!(bytes.decode_utf16().unwrap(), "hi");
+    let unpaired: TypedArray = Uint16Array::from(&[0x68, 0xd834, 0x69][..]).into();
+    assert_eq!(unpaired.decode_utf16().unwrap_err().index(), 1);
+    assert_eq!(unpaired.decode_utf16_lossy(), "h\u{fffd}i");
+    let odd: TypedArray = Uint8Array::from(&[b'h', 0, b'i'][..]).into();
+    assert_eq!(odd.decode_utf16().unwrap_err().index(), 1);
+    assert_eq!(odd.decode_utf16_lossy(), "h\u{fffd}");
+}
+
+#[wasm_bindgen_test]
+fn test_decode_latin1() {
+    let array: TypedArray = Uint8Array::from(&[b'c', 0xe9, 0x80, 0x9f][..]).into();
+    assert_eq!(array.decode_latin1(), "c\u{e9}\u{80}\u{9f}");
+    assert_eq!(array.decode_windows1252(), "c\u{e9}\u{20ac}\u{178}");
+}
+
+#[wasm_bindgen_test]
+fn test_bit_reader() {
+    let array: TypedArray = Uint8Array::from(&[0b1010_0011, 0b1111_0000][..]).into();
+    let mut reader = BitReader::new(&array, BitOrder::MsbFirst);
+    assert_eq!(reader.read_bits(3).unwrap(), 0b101);
+    assert!(!reader.read_bit().unwrap());
+    assert_eq!(reader.read_bits(8).unwrap(), 0b0011_1111);
+    assert_eq!(reader.position(), 12);
+    assert_eq!(reader.read_bits(5).unwrap_err().available(), 4);
+    assert_eq!(reader.position(), 12);
+    reader.align_to_byte();
+    assert_eq!(reader.remaining(), 0);
+
+    let mut reader = BitReader::new(&array, BitOrder::LsbFirst);
+    assert_eq!(reader.read_bits(3).unwrap(), 0b011);
+    reader.align_to_byte();
+    assert!(reader.is_aligned());
+    assert_eq!(reader.read_bits(8).unwrap(), 0b1111_0000);
+    let mut reader = BitReader::new(&array, BitOrder::LsbFirst);
+    assert_eq!(reader.read_bits(12).unwrap(), 0b0000_1010_0011);
+}
+
+#[wasm_bindgen_test]
+fn test_bit_reader_shrunk_buffer() {
+    let buffer = ArrayBuffer::new_with_options(8, &ArrayBufferOptions::new(8));
+    let array = TypedArray::new_length_tracking(TypedArrayKind::Uint8Array, &buffer, 0);
+    let mut reader = BitReader::new(&array, BitOrder::MsbFirst);
+    reader.read_bits(48).unwrap();
+    if array.is_resizable() {
+        array.resize_buffer(2).unwrap();
+        assert_eq!(reader.remaining(), 0);
+        let error = reader.read_bit().unwrap_err();
+        assert_eq!((error.position(), error.available()), (48, 0));
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_statistics() {
+    let array: TypedArray = Float32Array::from(&[2.0, f32::NAN, -1.0, 5.0][..]).into();
+    assert_eq!(array.min(NanMode::Ignore), Some(-1.0));
+    assert_eq!(array.max(NanMode::Ignore), Some(5.0));
+    assert_eq!(array.sum(NanMode::Ignore), 6.0);
+    assert_eq!(array.mean(NanMode::Ignore), Some(2.0));
+    assert!(array.min(NanMode::Propagate).unwrap().is_nan());
+    assert!(array.sum(NanMode::Propagate).is_nan());
+    assert!(array.mean(NanMode::Propagate).unwrap().is_nan());
+
+    let long: TypedArray = Uint8Array::new(&20000.into()).into();
+    long.fill(3.0, 0, 20000);
+    assert_eq!(long.sum(NanMode::Propagate), 60000.0);
+    let empty: TypedArray = Int16Array::new(&0.into()).into();
+    assert_eq!(empty.max(NanMode::Propagate), None);
+    assert_eq!(empty.mean(NanMode::Propagate), None);
+    assert_eq!(empty.sum(NanMode::Propagate), 0.0);
+}
+
+#[wasm_bindgen_test]
+fn test_variance() {
+    let array: TypedArray = Float64Array::from(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0][..]).into();
+    assert_eq!(array.variance(NanMode::Propagate), Some(4.0));
+    assert_eq!(array.std_dev(NanMode::Propagate), Some(2.0));
+    assert_eq!(array.sample_variance(NanMode::Propagate), Some(32.0 / 7.0));
+    assert_eq!(array.sample_std_dev(NanMode::Propagate), Some((32.0f64 / 7.0).sqrt()));
+    let single: TypedArray = Float32Array::from(&[1.0, f32::NAN][..]).into();
+    assert_eq!(single.variance(NanMode::Ignore), Some(0.0));
+    assert_eq!(single.sample_variance(NanMode::Ignore), None);
+    assert!(single.variance(NanMode::Propagate).unwrap().is_nan());
+}
+
+#[wasm_bindgen_test]
+fn test_histogram() {
+    let array: TypedArray = Float32Array::from(&[0.0, 1.0, 2.5, 4.0, f32::NAN][..]).into();
+    assert_eq!(array.histogram(4, None), vec![1, 1, 1, 1]);
+    assert_eq!(array.histogram(2, Some((0.0, 2.0))), vec![1, 1]);
+    let bytes: TypedArray = Uint8Array::from(&[7, 7, 7][..]).into();
+    assert_eq!(bytes.histogram(3, None), vec![0, 3, 0]);
+}
+
+#[wasm_bindgen_test]
+fn test_elementwise() {
+    let array: TypedArray = Int8Array::from(&[1, 2, 127][..]).into();
+    let other: TypedArray = Int8Array::from(&[3, -4, 1][..]).into();
+    array.add_assign(&other).unwrap();
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![4.0, -2.0, -128.0]);
+    array.sub_assign(&other).unwrap();
+    array.mul_assign(&other).unwrap();
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![3.0, -8.0, 127.0]);
+    assert_eq!(array.dot(&other), 9.0 + 32.0 + 127.0);
+
+    let clamped: TypedArray = Uint8ClampedArray::from(&[250, 5][..]).into();
+    let other: TypedArray = Uint8ClampedArray::from(&[10, 10][..]).into();
+    clamped.add_assign(&other).unwrap();
+    assert_eq!(clamped.iter().collect::<Vec<_>>(), vec![255.0, 15.0]);
+    let floats: TypedArray = Float64Array::from(&[0.5, 2.0][..]).into();
+    assert_eq!(floats.dot(&other), 25.0);
+    assert!(floats.add_assign(&other).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_scale_offset() {
+    let array: TypedArray = Float32Array::from(&[1.0, -2.0][..]).into();
+    array.scale_offset(0.5, 1.0);
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![1.5, 0.0]);
+    let bytes: TypedArray = Uint8Array::from(&[10, 200][..]).into();
+    bytes.scale_offset(2.0, -30.0);
+    assert_eq!(bytes.iter().collect::<Vec<_>>(), vec![0.0, 255.0]);
+}
+
+#[wasm_bindgen_test]
+fn test_clamp_in_place() {
+    let array: TypedArray = Float64Array::from(&[-0.5, 0.25, 1.5, f64::NAN][..]).into();
+    array.clamp_in_place(0.0, 1.0);
+    let values = array.iter().collect::<Vec<_>>();
+    assert_eq!(values[..3], [0.0, 0.25, 1.0]);
+    assert!(values[3].is_nan());
+    let ints: TypedArray = Int16Array::from(&[-300, 5, 300][..]).into();
+    ints.clamp_in_place(-100.0, 1e9);
+    assert_eq!(ints.iter().collect::<Vec<_>>(), vec![-100.0, 5.0, 300.0]);
+}
+
+#[wasm_bindgen_test]
+fn test_apply() {
+    let array: TypedArray = Int32Array::new(&20000.into()).into();
+    let mut counter = 0.0;
+    array.apply(|x| {
+        counter += 1.0;
+        x + counter
+    });
+    assert_eq!(array.first(), Some(1.0));
+    assert_eq!(array.last(), Some(20000.0));
+    let floats: TypedArray = Float32Array::from(&[4.0, 9.0][..]).into();
+    floats.apply(f64::sqrt);
+    assert_eq!(floats.iter().collect::<Vec<_>>(), vec![2.0, 3.0]);
+}
+
+#[wasm_bindgen_test]
+fn test_normalize() {
+    let array: TypedArray = Float32Array::from(&[2.0, 4.0, 6.0][..]).into();
+    array.normalize((0.0, 1.0));
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![0.0, 0.5, 1.0]);
+    let bytes: TypedArray = Uint8Array::from(&[0, 128, 255][..]).into();
+    let floats = bytes.convert_to(TypedArrayKind::Float64Array, ConversionMode::Truncate);
+    floats.normalize_from((0.0, 255.0), (-1.0, 1.0));
+    assert_eq!(floats.first(), Some(-1.0));
+    assert_eq!(floats.last(), Some(1.0));
+    let constant: TypedArray = Int16Array::from(&[7, 7][..]).into();
+    constant.normalize((-5.0, 5.0));
+    assert_eq!(constant.iter().collect::<Vec<_>>(), vec![-5.0, -5.0]);
+}
+
+#[wasm_bindgen_test]
+fn test_binary_search() {
+    let array: TypedArray = Float64Array::from(&[-1.0, 0.5, 2.0, 8.0][..]).into();
+    assert_eq!(array.binary_search(2.0), Ok(2));
+    assert_eq!(array.binary_search(1.0), Err(2));
+    assert_eq!(array.binary_search(-5.0), Err(0));
+    assert_eq!(array.binary_search(10.0), Err(4));
+    assert_eq!(array.binary_search_many(&[8.0, 0.0]), vec![Ok(3), Err(1)]);
+    let nan: TypedArray = Float64Array::from(&[-f64::NAN, 1.0, -0.0][..]).into();
+    nan.sort();
+    assert_eq!(nan.binary_search(-f64::NAN), Ok(2));
+    assert_eq!(nan.binary_search_many(&[-f64::NAN, 0.0]), vec![Ok(2), Err(1)]);
+    let empty: TypedArray = Int32Array::new(&0.into()).into();
+    assert_eq!(empty.binary_search(1.0), Err(0));
+}
+
+#[wasm_bindgen_test]
+fn test_gather() {
+    let array: TypedArray = Int16Array::from(&[10, 20, 30][..]).into();
+    let gathered = array.gather(&Uint32Array::from(&[2, 0, 2][..])).unwrap();
+    assert_eq!(gathered.kind(), TypedArrayKind::Int16Array);
+    assert_eq!(gathered.iter().collect::<Vec<_>>(), vec![30.0, 10.0, 30.0]);
+    match array.gather(&Uint32Array::from(&[1, 3][..])) {
+        Err(GatherError::OutOfBounds(error)) => {
+            assert_eq!((error.index(), error.length()), (3, 3))
+        }
+        result => panic!("unexpected result {:?}", result),
+    }
+    typed_array::set_allocation_limit(Some(4));
+    let result = array.gather(&Uint32Array::from(&[0, 1, 2][..]));
+    typed_array::set_allocation_limit(None);
+    assert!(matches!(result, Err(GatherError::AllocationLimit(_))));
+}
+
+#[wasm_bindgen_test]
+fn test_scatter() {
+    let array: TypedArray = Float32Array::from(&[0.0, 0.0, 0.0][..]).into();
+    let values: TypedArray = Float32Array::from(&[1.5, 2.5][..]).into();
+    array
+        .scatter(&Uint32Array::from(&[2, 0][..]), &values)
+        .unwrap();
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![2.5, 0.0, 1.5]);
+    assert!(array
+        .scatter(&Uint32Array::from(&[0, 3][..]), &values)
+        .is_err());
+    let other: TypedArray = Int8Array::from(&[1, 2][..]).into();
+    assert!(array
+        .scatter(&Uint32Array::from(&[0, 1][..]), &other)
+        .is_err());
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![2.5, 0.0, 1.5]);
+}
+
+#[wasm_bindgen_test]
+fn test_argmin_argmax_argsort() {
+    let array: TypedArray = Float64Array::from(&[3.0, f64::NAN, -1.0, 5.0, -1.0][..]).into();
+    assert_eq!(array.argmin(), Some(2));
+    assert_eq!(array.argmax(), Some(3));
+    let order = array.argsort();
+    assert_eq!(order.to_vec(), vec![2, 4, 0, 3, 1]);
+    let empty: TypedArray = Int8Array::new_with_length(0).into();
+    assert_eq!(empty.argmin(), None);
+    assert_eq!(empty.argsort().length(), 0);
+}
+
+#[wasm_bindgen_test]
+fn test_cumsum() {
+    let array: TypedArray = Uint32Array::from(&[3, 0, 4, 1][..]).into();
+    let sums = array.cumsum();
+    assert_eq!(sums.iter().collect::<Vec<_>>(), vec![3.0, 3.0, 7.0, 8.0]);
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![3.0, 0.0, 4.0, 1.0]);
+    let clamped: TypedArray = Uint8ClampedArray::from(&[200, 100, 7][..]).into();
+    clamped.cumsum_in_place();
+    assert_eq!(clamped.iter().collect::<Vec<_>>(), vec![200.0, 255.0, 255.0]);
+    let maxima: TypedArray = Float64Array::from(&[1.0, 3.0, 2.0, 5.0][..]).into();
+    maxima.prefix_scan(f64::max);
+    assert_eq!(maxima.iter().collect::<Vec<_>>(), vec![1.0, 3.0, 3.0, 5.0]);
+}
+
+#[wasm_bindgen_test]
+fn test_rotate() {
+    let array: TypedArray = Int32Array::from(&[1, 2, 3, 4, 5][..]).into();
+    array.rotate_left(2);
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![3.0, 4.0, 5.0, 1.0, 2.0]);
+    array.rotate_left(4);
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![2.0, 3.0, 4.0, 5.0, 1.0]);
+    array.rotate_right(6);
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+    let empty: TypedArray = Int32Array::new_with_length(0).into();
+    empty.rotate_right(3);
+    assert_eq!(empty.length(), 0);
+}
+
+#[wasm_bindgen_test]
+fn test_transform_clamped_rounding() {
+    let array: TypedArray = Uint8ClampedArray::from(&[1, 2, 3, 4, 200][..]).into();
+    array.scale_offset(1.0, 0.5);
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![2.0, 2.0, 4.0, 4.0, 200.0]);
+    array.scale_offset(1.35, 0.0);
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![3.0, 3.0, 5.0, 5.0, 255.0]);
+    array.clamp_in_place(3.2, 4.7);
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![3.0, 3.0, 5.0, 5.0, 5.0]);
+    array.apply(|x| x - 5.5);
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![0.0, 0.0, 0.0, 0.0, 0.0]);
+    array.apply(|_| 2.7);
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![3.0, 3.0, 3.0, 3.0, 3.0]);
+    let truncated: TypedArray = Uint8Array::from(&[1][..]).into();
+    truncated.apply(|_| 2.7);
+    assert_eq!(truncated.iter().collect::<Vec<_>>(), vec![2.0]);
+}
+
+#[wasm_bindgen_test]
+fn test_fill() {
+    let array: TypedArray = Int16Array::new(&4.into()).into();
+    array.fill(7.0, 1, 3);
+    assert_eq!(Int16Array::try_from(array).unwrap().to_vec(), vec![0, 7, 7, 0]);
+    let clamped: TypedArray = Uint8ClampedArray::new(&3.into()).into();
+    clamped.fill(2.7, 0, 2).fill(0.5, 2, 3);
+    assert_eq!(Uint8ClampedArray::try_from(clamped).unwrap().to_vec(), vec![3, 3, 0]);
+}
+
+#[wasm_bindgen_test]
+fn test_fill_with() {
+    let array: TypedArray = Float32Array::new(&4.into()).into();
+    array.fill_with(|i| f64::from(i) * 0.5);
+    assert_eq!(
+        Float32Array::try_from(array).unwrap().to_vec(),
+        vec![0.0, 0.5, 1.0, 1.5]
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_fill_with_clamped() {
+    let array: TypedArray = Uint8ClampedArray::new(&5.into()).into();
+    let values = [2.5, 2.7, 3.5, 300.0, -1.0];
+    array.fill_with(|i| values[i as usize]);
+    assert_eq!(
+        Uint8ClampedArray::try_from(array).unwrap().to_vec(),
+        vec![2, 3, 4, 255, 0]
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_copy_within() {
+    let array: TypedArray = Uint8Array::from(&[1, 2, 3, 4, 5][..]).into();
+    array.copy_within(0, 3, 5);
+    assert_eq!(
+        Uint8Array::try_from(array).unwrap().to_vec(),
+        vec![4, 5, 3, 4, 5]
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_reverse() {
+    let array: TypedArray = Int32Array::from(&[1, 2, 3][..]).into();
+    array.reverse();
+    assert_eq!(Int32Array::try_from(array).unwrap().to_vec(), vec![3, 2, 1]);
+}
+
+#[wasm_bindgen_test]
+fn test_sort() {
+    let array: TypedArray = Float64Array::from(&[3.0, -1.0, 10.0, 2.5][..]).into();
+    array.sort();
+    assert_eq!(
+        Float64Array::try_from(array).unwrap().to_vec(),
+        vec![-1.0, 2.5, 3.0, 10.0]
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_sort_by() {
+    let array: TypedArray = Int8Array::from(&[3, -5, 1, 4][..]).into();
+    array.sort_by(|a, b| b.partial_cmp(&a).unwrap());
+    assert_eq!(Int8Array::try_from(array).unwrap().to_vec(), vec![4, 3, 1, -5]);
+}
+
+#[wasm_bindgen_test]
+fn test_search() {
+    let array: TypedArray = Uint16Array::from(&[1, 3, 5, 3][..]).into();
+    assert_eq!(array.index_of(3.0, 0), Some(1));
+    assert_eq!(array.index_of(3.0, 2), Some(3));
+    assert_eq!(array.index_of(4.0, 0), None);
+    assert_eq!(array.last_index_of(3.0, 3), Some(3));
+    assert_eq!(array.last_index_of(1.0, 3), Some(0));
+    assert!(array.includes(5.0, 0));
+    assert!(!array.includes(5.0, 3));
+}
+
+#[wasm_bindgen_test]
+fn test_find() {
+    let array: TypedArray = Float32Array::from(&[0.5, -2.0, 4.0, -8.0][..]).into();
+    assert_eq!(array.find(|x, _| x < 0.0), Some(-2.0));
+    assert_eq!(array.find(|_, i| i > 3), None);
+    assert_eq!(array.find_index(|x, _| x > 1.0), Some(2));
+    assert_eq!(array.find_index(|x, _| x.is_nan()), None);
+}
+
+#[wasm_bindgen_test]
+fn test_every_some() {
+    let array: TypedArray = Float64Array::from(&[0.25, -1.0, 1.0][..]).into();
+    assert!(array.every(|x, _| (-1.0..=1.0).contains(&x)));
+    assert!(!array.every(|x, _| x > 0.0));
+    assert!(array.some(|x, _| x < 0.0));
+    assert!(!array.some(|_, i| i > 2));
+}
+
+#[wasm_bindgen_test]
+fn test_join() {
+    let array: TypedArray = Int16Array::from(&[1, -2, 3][..]).into();
+    assert_eq!(array.join(", "), "1, -2, 3");
+    let empty: TypedArray = Int16Array::new(&0.into()).into();
+    assert_eq!(empty.join(","), "");
+}
+
+#[wasm_bindgen_test]
+fn test_to_locale_string() {
+    let array: TypedArray = Uint32Array::from(&[1000, 2][..]).into();
+    let string: String = array
+        .to_locale_string(&"en-US".into(), &JsValue::UNDEFINED)
+        .into();
+    assert_eq!(string, "1,000,2");
+}
+
+#[wasm_bindgen_test]
+fn test_for_each() {
+    let array: TypedArray = Uint8ClampedArray::from(&[10, 20, 30][..]).into();
+    let mut visited = Vec::new();
+    array.for_each(|x, i| visited.push((x, i)));
+    assert_eq!(visited, vec![(10.0, 0), (20.0, 1), (30.0, 2)]);
+}
+
+#[wasm_bindgen_test]
+fn test_map() {
+    let array: TypedArray = Int32Array::from(&[1, 2, 3][..]).into();
+    let mapped = array.map(|x, i| x * 10.0 + f64::from(i));
+    assert_eq!(Int32Array::try_from(mapped).unwrap().to_vec(), vec![10, 21, 32]);
+    assert_eq!(Int32Array::try_from(array).unwrap().to_vec(), vec![1, 2, 3]);
+}
+
+#[wasm_bindgen_test]
+fn test_filter() {
+    let array: TypedArray = Float32Array::from(&[1.0, f32::NAN, 3.0, f32::NAN][..]).into();
+    let filtered = array.filter(|x, _| !x.is_nan());
+    assert_eq!(Float32Array::try_from(filtered).unwrap().to_vec(), vec![1.0, 3.0]);
+    let odd = array.filter(|_, i| i % 2 == 1);
+    assert_eq!(odd.length(), 2);
+}
+
+#[wasm_bindgen_test]
+fn test_reduce() {
+    let array: TypedArray = Uint8Array::from(&[1, 2, 3, 4][..]).into();
+    assert_eq!(array.reduce(0.0, |sum, x, _| sum + x), 10.0);
+    assert_eq!(
+        array.reduce(Vec::new(), |mut order, _, i| {
+            order.push(i);
+            order
+        }),
+        vec![0, 1, 2, 3]
+    );
+    assert_eq!(
+        array.reduce_right(Vec::new(), |mut order, x, _| {
+            order.push(x);
+            order
+        }),
+        vec![4.0, 3.0, 2.0, 1.0]
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_reduce_large() {
+    let array: TypedArray = Uint32Array::new(&100_000.into()).into();
+    array.fill_with(f64::from);
+    let expected = (0..100_000u64).sum::<u64>() as f64;
+    assert_eq!(array.reduce(0.0, |sum, x, _| sum + x), expected);
+    assert_eq!(array.reduce_right(0.0, |sum, x, _| sum + x), expected);
+    assert_eq!(array.reduce_right(None, |last, _, i| last.or(Some(i))), Some(99_999));
+}
+
+#[wasm_bindgen_test]
+fn test_js_iterators() {
+    let array: TypedArray = Int8Array::from(&[5, -6][..]).into();
+    let keys: Vec<_> = array.keys().into_iter().map(|k| k.unwrap().as_f64()).collect();
+    assert_eq!(keys, vec![Some(0.0), Some(1.0)]);
+    let values: Vec<_> = array.values().into_iter().map(|v| v.unwrap().as_f64()).collect();
+    assert_eq!(values, vec![Some(5.0), Some(-6.0)]);
+    let entries = Array::from(&array.entries());
+    assert_eq!(entries.length(), 2);
+    assert_eq!(Array::from(&entries.get(1)).get(1).as_f64(), Some(-6.0));
+}
+
+#[wasm_bindgen_test]
+fn test_iter() {
+    let array: TypedArray = Int16Array::from(&[-1, 0, 1][..]).into();
+    assert_eq!(array.get_index(2), 1.0);
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![-1.0, 0.0, 1.0]);
+    let mut sum = 0.0;
+    for x in &array {
+        sum += x;
+    }
+    assert_eq!(sum, 0.0);
+}
+
+#[wasm_bindgen_test]
+fn test_iter_double_ended() {
+    let array: TypedArray = Uint8Array::from(&[1, 2, 3, 4, 5][..]).into();
+    let mut iter = array.iter();
+    assert_eq!(iter.len(), 5);
+    assert_eq!(iter.next_back(), Some(5.0));
+    assert_eq!(iter.next(), Some(1.0));
+    assert_eq!(iter.len(), 3);
+    assert_eq!(iter.rev().collect::<Vec<_>>(), vec![4.0, 3.0, 2.0]);
+    assert_eq!(array.iter().nth(3), Some(4.0));
+    assert_eq!(array.iter().nth(5), None);
+    let pairs: Vec<_> = array.iter().zip(array.iter().rev()).collect();
+    assert_eq!(pairs[0], (1.0, 5.0));
+}
+
+#[wasm_bindgen_test]
+fn test_iter_chunks() {
+    let array: TypedArray = Int32Array::from(&[1, 2, 3, 4, 5][..]).into();
+    let mut chunks = array.iter_chunks(2);
+    assert_eq!(chunks.next_chunk(), Some(&[1.0, 2.0][..]));
+    assert_eq!(chunks.next_chunk(), Some(&[3.0, 4.0][..]));
+    assert_eq!(chunks.next_chunk(), Some(&[5.0][..]));
+    assert_eq!(chunks.next_chunk(), None);
+}
+
+#[wasm_bindgen_test]
+fn test_ranges() {
+    let array: TypedArray = Uint8Array::from(&[0, 1, 2, 3, 4, 5][..]).into();
+    let collect = |array: TypedArray| array.iter().collect::<Vec<_>>();
+    assert_eq!(collect(array.subarray_range(1..3)), vec![1.0, 2.0]);
+    assert_eq!(collect(array.subarray_range(1..=3)), vec![1.0, 2.0, 3.0]);
+    assert_eq!(collect(array.slice_range(4..)), vec![4.0, 5.0]);
+    assert_eq!(collect(array.slice_range(..2)), vec![0.0, 1.0]);
+    assert_eq!(array.slice_range(..).length(), 6);
+}
+
+#[wasm_bindgen_test]
+fn test_checked_subarray() {
+    let array: TypedArray = Float64Array::new(&4.into()).into();
+    assert_eq!(array.checked_subarray(1, 4).unwrap().length(), 3);
+    assert_eq!(array.checked_subarray(4, 4).unwrap().length(), 0);
+    let error = array.checked_subarray(3, 5).unwrap_err();
+    assert_eq!((error.begin(), error.end(), error.length()), (3, 5, 4));
+    assert_eq!(
+        error.to_string(),
+        "range 3..5 is invalid for a typed array of length 4"
+    );
+    assert!(array.checked_subarray(3, 2).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_subarray_from() {
+    let array: TypedArray = Int8Array::from(&[1, 2, 3, 4][..]).into();
+    let view = array.subarray_from(1);
+    assert!(Int8Array::try_from(view.clone()).is_ok());
+    assert_eq!(view.iter().collect::<Vec<_>>(), vec![2.0, 3.0, 4.0]);
+    view.fill(0.0, 0, 3);
+    assert_eq!(array.get_index(3), 0.0);
+    let copy = array.slice_from(3);
+    copy.fill(9.0, 0, 1);
+    assert_eq!(copy.length(), 1);
+    assert_eq!(array.get_index(3), 0.0);
+}
+
+#[wasm_bindgen_test]
+fn test_split_at() {
+    let array: TypedArray = Uint8Array::from(&[1, 2, 3, 4, 5][..]).into();
+    let (header, payload) = array.split_at(2);
+    assert_eq!(header.iter().collect::<Vec<_>>(), vec![1.0, 2.0]);
+    assert_eq!(payload.iter().collect::<Vec<_>>(), vec![3.0, 4.0, 5.0]);
+    assert_eq!(payload.byte_offset(), 2);
+    let (all, none) = array.split_at(5);
+    assert_eq!((all.length(), none.length()), (5, 0));
+}
+
+#[wasm_bindgen_test]
+fn test_split_off() {
+    let array: TypedArray = Uint16Array::from(&[1, 2, 3][..]).into();
+    let tail = array.split_off(1);
+    tail.fill(0.0, 0, 2);
+    assert_eq!(array.iter().collect::<Vec<_>>(), vec![1.0, 2.0, 3.0]);
+    assert_eq!(tail.length(), 2);
+    assert_eq!(tail.byte_offset(), 0);
+}
+
+#[wasm_bindgen_test]
+fn test_chunks() {
+    let array: TypedArray = Uint8Array::new(&10.into()).into();
+    let chunks = array.chunks(4);
+    assert_eq!(chunks.len(), 3);
+    let lengths: Vec<_> = chunks.map(|chunk| chunk.length()).collect();
+    assert_eq!(lengths, vec![4, 4, 2]);
+    let offsets: Vec<_> = array.chunks(5).map(|chunk| chunk.byte_offset()).collect();
+    assert_eq!(offsets, vec![0, 5]);
+}
+
+#[wasm_bindgen_test]
+fn test_windows() {
+    let array: TypedArray = Int16Array::from(&[1, 2, 3, 4][..]).into();
+    let windows = array.windows(3);
+    assert_eq!(windows.len(), 2);
+    let sums: Vec<_> = windows
+        .map(|window| window.reduce(0.0, |sum, x, _| sum + x))
+        .collect();
+    assert_eq!(sums, vec![6.0, 9.0]);
+    assert_eq!(array.windows(5).count(), 0);
+    assert_eq!(array.windows(1).len(), 4);
+}
+
+#[wasm_bindgen_test]
+fn test_first_last() {
+    let array: TypedArray = Float32Array::from(&[1.5, 2.5, 3.5][..]).into();
+    assert!(!array.is_empty());
+    assert_eq!(array.first(), Some(1.5));
+    assert_eq!(array.last(), Some(3.5));
+    let empty: TypedArray = Float32Array::new(&0.into()).into();
+    assert!(empty.is_empty());
+    assert_eq!(empty.first(), None);
+    assert_eq!(empty.last(), None);
+}
+
+#[wasm_bindgen_test]
+fn test_concat() {
+    let parts = [
+        TypedArray::from(Uint8Array::from(&[1, 2][..])),
+        TypedArray::from(Uint8Array::new(&0.into())),
+        TypedArray::from(Uint8Array::from(&[3][..])),
+    ];
+    let joined = TypedArray::concat(&parts).unwrap();
+    assert_eq!(joined.kind(), TypedArrayKind::Uint8Array);
+    assert_eq!(joined.iter().collect::<Vec<_>>(), vec![1.0, 2.0, 3.0]);
+
+    let mixed = [
+        TypedArray::from(Uint8Array::new(&1.into())),
+        TypedArray::from(Int8Array::new(&1.into())),
+    ];
+    match TypedArray::concat(&mixed).unwrap_err() {
+        ConcatError::KindMismatch(error) => {
+            assert_eq!(error.expected(), TypedArrayKind::Uint8Array);
+            assert_eq!(error.found(), TypedArrayKind::Int8Array);
+        }
+        error => panic!("unexpected error: {}", error),
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_new_with_length() {
+    let array = TypedArray::new_with_length(TypedArrayKind::Float32Array, 3);
+    assert_eq!(array.kind(), TypedArrayKind::Float32Array);
+    assert_eq!(array.byte_length(), 12);
+}
+
+#[wasm_bindgen_test]
+fn test_repeat() {
+    let array: TypedArray = Int8Array::from(&[1, -1][..]).into();
+    let tiled = array.repeat(3);
+    assert_eq!(tiled.kind(), TypedArrayKind::Int8Array);
+    assert_eq!(
+        tiled.iter().collect::<Vec<_>>(),
+        vec![1.0, -1.0, 1.0, -1.0, 1.0, -1.0]
+    );
+    assert!(array.repeat(0).is_empty());
+}
+
+#[wasm_bindgen_test]
+fn test_as_bytes() {
+    let array: TypedArray = Uint16Array::from(&[0x0102, 0x0304, 0x0506][..]).into();
+    let view = array.subarray(1, 3);
+    let bytes = view.as_bytes();
+    assert_eq!(bytes.byte_offset(), 2);
+    assert_eq!(bytes.length(), 4);
+    assert_eq!(bytes.to_vec(), vec![0x04, 0x03, 0x06, 0x05]);
+    bytes.set_index(0, 0xff);
+    assert_eq!(array.get_index(1), f64::from(0x03ff));
+    assert_eq!(view.as_bytes_typed_array().kind(), TypedArrayKind::Uint8Array);
+}
+
+#[wasm_bindgen_test]
+fn test_byte_subarray() {
+    let array: TypedArray = Uint32Array::from(&[1, 2, 3, 4][..]).into();
+    assert_eq!(array.bytes_per_element(), 4);
+    let view = array.byte_subarray(4, 12).unwrap();
+    assert_eq!(view.iter().collect::<Vec<_>>(), vec![2.0, 3.0]);
+    let error = array.byte_subarray(2, 8).unwrap_err();
+    assert_eq!(error.bytes_per_element(), 4);
+    assert!(array.byte_subarray(4, 6).is_err());
+    assert!(array.byte_subarray(8, 4).is_err());
+    assert!(array.byte_subarray(0, 20).is_err());
+    assert!(view.byte_subarray(0, 8).is_ok());
+    assert!(view.byte_subarray(0, 12).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_convert_to() {
+    let floats: TypedArray = Float64Array::from(&[-1.5, 0.5, 2.5, 300.7, f64::NAN][..]).into();
+    let convert = |mode| {
+        let converted = floats.convert_to(TypedArrayKind::Uint8Array, mode);
+        assert_eq!(converted.kind(), TypedArrayKind::Uint8Array);
+        Uint8Array::try_from(converted).unwrap().to_vec()
+    };
+    assert_eq!(convert(ConversionMode::Truncate), vec![255, 0, 2, 44, 0]);
+    assert_eq!(convert(ConversionMode::Round), vec![254, 1, 3, 45, 0]);
+    assert_eq!(convert(ConversionMode::Saturate), vec![0, 1, 3, 255, 0]);
+    assert_eq!(convert(ConversionMode::Normalize), vec![0, 128, 255, 255, 0]);
+
+    let signed: TypedArray = Int8Array::from(&[-128, -127, 0, 127][..]).into();
+    let normalized = signed.convert_to(TypedArrayKind::Float32Array, ConversionMode::Normalize);
+    assert_eq!(Float32Array::try_from(normalized).unwrap().to_vec(), vec![-1.0, -1.0, 0.0, 1.0]);
+    let widened = signed.convert_to(TypedArrayKind::Int16Array, ConversionMode::Normalize);
+    assert_eq!(Int16Array::try_from(widened).unwrap().to_vec(), vec![-32767, -32767, 0, 32767]);
+
+    let large: TypedArray = Float64Array::from(&[1e300, 0.1][..]).into();
+    let single = large.convert_to(TypedArrayKind::Float32Array, ConversionMode::Saturate);
+    assert_eq!(single.get_index(0), f64::from(f32::MAX));
+    assert_eq!(single.get_index(1), f64::from(0.1f32));
+}
+
+#[wasm_bindgen_test]
+fn test_reinterpret_as() {
+    let array: TypedArray = Uint8Array::new_with_length(12).into();
+    let floats = array.subarray(4, 12).reinterpret_as(TypedArrayKind::Float32Array).unwrap();
+    assert_eq!(floats.kind(), TypedArrayKind::Float32Array);
+    assert_eq!((floats.byte_offset(), floats.length()), (4, 2));
+    floats.fill(1.5, 1, 2);
+    assert_eq!(array.read_f32_le(8), 1.5);
+    let error = array.subarray(2, 10).reinterpret_as(TypedArrayKind::Float64Array).unwrap_err();
+    assert_eq!((error.kind(), error.byte_offset(), error.byte_length()), (TypedArrayKind::Float64Array, 2, 8));
+    assert!(array.subarray(0, 6).reinterpret_as(TypedArrayKind::Uint32Array).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_data_view() {
+    let array: TypedArray = Uint8Array::from(&[0, 1, 2, 3, 4, 5][..]).into();
+    let view = array.subarray(2, 6);
+    let data_view = view.data_view();
+    assert_eq!(data_view.byte_offset(), 2);
+    assert_eq!(data_view.byte_length(), 4);
+    assert_eq!(data_view.get_uint16(0), 0x0203);
+    let ranged = view.data_view_range(1, 2);
+    assert_eq!(ranged.byte_offset(), 3);
+    assert_eq!(ranged.get_uint16_endian(0, true), 0x0403);
+}
+
+#[wasm_bindgen_test]
+fn test_endian() {
+    let array: TypedArray = Uint8Array::from(&[0x12, 0x34, 0x56, 0x78, 0, 0, 0, 0, 0, 0][..]).into();
+    assert_eq!(array.read_u16_le(0), 0x3412);
+    assert_eq!(array.read_u16_be(1), 0x3456);
+    assert_eq!(array.read_u32_be(0), 0x1234_5678);
+    assert_eq!(array.read_u32_le(0), 0x7856_3412);
+    array.write_u32_be(2, 0xdead_beef);
+    assert_eq!(array.read_u16_le(4), 0xefbe);
+    array.write_f32_le(0, 1.5);
+    assert_eq!(array.read_f32_le(0), 1.5);
+    assert_ne!(array.read_f32_be(0), 1.5);
+    let floats: TypedArray = Float64Array::new(&2.into()).into();
+    floats.write_f64_be(8, -2.25);
+    assert_eq!(floats.read_f64_be(8), -2.25);
+    floats.write_f64_le(0, 0.1);
+    assert_eq!(floats.get_index(0), 0.1);
+    floats.write_u16_be(0, 1);
+    assert_eq!(array.subarray(0, 2).as_bytes().to_vec(), vec![0, 0]);
+}
+
+#[wasm_bindgen_test]
+fn test_swap_bytes() {
+    let array: TypedArray = Uint16Array::from(&[0x0102, 0x0304, 0x0506][..]).into();
+    array.subarray(1, 3).swap_bytes();
+    assert_eq!(Uint16Array::try_from(array).unwrap().to_vec(), vec![0x0102, 0x0403, 0x0605]);
+    let floats: TypedArray = Float64Array::new(&1.into()).into();
+    floats.write_f64_be(0, 2.5);
+    floats.swap_bytes();
+    assert_eq!(floats.get_index(0), 2.5);
+    let bytes: TypedArray = Uint8Array::from(&[1, 2][..]).into();
+    bytes.swap_bytes();
+    assert_eq!(bytes.get_index(0), 1.0);
+}
+
+#[wasm_bindgen_test]
+fn test_reader() {
+    let array: TypedArray = Uint8Array::from(&[7, 1, 0, 0, 0, 0xaa, 0xbb, 0xcc][..]).into();
+    let mut reader = TypedArrayReader::new(&array);
+    assert_eq!(reader.remaining(), 8);
+    assert_eq!(reader.read_u8(), Ok(7));
+    assert_eq!(reader.read_u32_le(), Ok(1));
+    assert_eq!(reader.position(), 5);
+    reader.skip(1).unwrap();
+    assert_eq!(reader.read_bytes(2).unwrap().to_vec(), vec![0xbb, 0xcc]);
+    assert_eq!(reader.remaining(), 0);
+    let error = reader.read_u16_be().unwrap_err();
+    assert_eq!((error.position(), error.requested(), error.available()), (8, 2, 0));
+    assert_eq!(reader.position(), 8);
+}
+
+#[wasm_bindgen_test]
+fn test_reader_shrunk_buffer() {
+    let buffer = ArrayBuffer::new_with_options(8, &ArrayBufferOptions::new(8));
+    let array = TypedArray::new_length_tracking(TypedArrayKind::Uint8Array, &buffer, 0);
+    let mut reader = TypedArrayReader::new(&array);
+    reader.skip(6).unwrap();
+    if array.is_resizable() {
+        array.resize_buffer(2).unwrap();
+        assert_eq!(reader.remaining(), 0);
+        let error = reader.read_u8().unwrap_err();
+        assert_eq!((error.position(), error.available()), (6, 0));
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_reader_view() {
+    let array: TypedArray = Uint16Array::from(&[0x0102, 0x0304][..]).into();
+    let mut reader = TypedArrayReader::new(&array.subarray(1, 2));
+    assert_eq!(reader.read_u16_le(), Ok(0x0304));
+    assert!(reader.skip(1).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_writer() {
+    let array: TypedArray = Uint8Array::new_with_length(8).into();
+    let mut writer = TypedArrayWriter::new(&array);
+    writer.write_u8(7).unwrap();
+    writer.write_u32_le(1).unwrap();
+    writer.skip(1).unwrap();
+    writer.write_bytes(&[0xbb, 0xcc]).unwrap();
+    assert_eq!(writer.remaining(), 0);
+    let error = writer.write_u16_be(0).unwrap_err();
+    assert_eq!((error.position(), error.requested(), error.available()), (8, 2, 0));
+    assert_eq!(
+        Uint8Array::try_from(array).unwrap().to_vec(),
+        vec![7, 1, 0, 0, 0, 0, 0xbb, 0xcc]
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_writer_round_trip() {
+    let array: TypedArray = Float32Array::new_with_length(4).into();
+    let mut writer = TypedArrayWriter::new(&array.subarray(1, 4));
+    writer.write_f64_be(1.5).unwrap();
+    assert!(writer.write_bytes(&[0; 5]).is_err());
+    assert_eq!(writer.position(), 8);
+    let mut reader = TypedArrayReader::new(&array);
+    reader.skip(4).unwrap();
+    assert_eq!(reader.read_f64_be(), Ok(1.5));
+}